@@ -1,11 +1,20 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use itertools::Itertools;
-use poker::{cards, deck, evaluate::static_lookup, Evaluator};
+use poker::{cards, deck, evaluate::static_lookup, Card, Eval, Evaluator, SevenCardEvaluator};
 
 fn bench_evaluator(c: &mut Criterion) {
     c.bench_function("Evaluator::new()", |b| b.iter(Evaluator::new));
 }
 
+fn bench_seven_card_evaluator(c: &mut Criterion) {
+    let mut group = c.benchmark_group("SevenCardEvaluator::new()");
+    group.sample_size(10);
+    group.bench_function("SevenCardEvaluator::new()", |b| {
+        b.iter(SevenCardEvaluator::new)
+    });
+    group.finish();
+}
+
 fn bench_single_5card_hand_eval(c: &mut Criterion) {
     let mut group = c.benchmark_group("single_5card_hand_eval");
 
@@ -47,6 +56,67 @@ fn bench_single_7card_hand_eval(c: &mut Criterion) {
         })
     });
 
+    let seven_card_eval = SevenCardEvaluator::new();
+    group.bench_function("seven_card", |b| {
+        b.iter(|| {
+            let _ = seven_card_eval.evaluate(&hand);
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_many_7card_eval(c: &mut Criterion) {
+    let mut group = c.benchmark_group("many_7card_eval");
+    group.sample_size(10);
+    let eval = Evaluator::new();
+    let seven_card_eval = SevenCardEvaluator::new();
+    let hands = (0..10_000)
+        .map(|seed| deck::Deck::from_seed(seed).draw(7).unwrap())
+        .collect::<Box<_>>();
+
+    group.bench_function("dynamic", |b| {
+        b.iter(|| {
+            for hand in hands.iter() {
+                let _ = eval.evaluate(hand);
+            }
+        })
+    });
+
+    group.bench_function("static", |b| {
+        b.iter(|| {
+            for hand in hands.iter() {
+                let _ = static_lookup::evaluate(hand);
+            }
+        })
+    });
+
+    group.bench_function("seven_card", |b| {
+        b.iter(|| {
+            for hand in hands.iter() {
+                let _ = seven_card_eval.evaluate(hand);
+            }
+        })
+    });
+
+    let hands = hands
+        .iter()
+        .map(|hand| <[Card; 7]>::try_from(hand.as_slice()).unwrap())
+        .collect::<Box<_>>();
+    let mut results = vec![Eval::WORST; hands.len()];
+
+    group.bench_function("dynamic_many", |b| {
+        b.iter(|| eval.evaluate_many(&hands, &mut results))
+    });
+
+    group.bench_function("dynamic_many_unique", |b| {
+        b.iter(|| eval.evaluate_many_unique(&hands, &mut results))
+    });
+
+    group.bench_function("static_many", |b| {
+        b.iter(|| static_lookup::evaluate_many(&hands, &mut results))
+    });
+
     group.finish();
 }
 
@@ -56,17 +126,13 @@ fn bench_all_5card_eval(c: &mut Criterion) {
     let eval = Evaluator::new();
     let gen = deck::generate().combinations(5).collect::<Box<_>>();
 
-    let routine = {
-        let eval = eval;
-        let gen = &gen;
-        move || {
+    group.bench_function("dynamic", |b| {
+        b.iter(|| {
             for cards in gen.iter() {
                 let _ = eval.evaluate(cards);
             }
-        }
-    };
-
-    group.bench_function("dynamic", |b| b.iter(&routine));
+        })
+    });
 
     group.bench_function("static", |b| {
         b.iter(|| {
@@ -76,14 +142,30 @@ fn bench_all_5card_eval(c: &mut Criterion) {
         });
     });
 
+    let hands = gen
+        .iter()
+        .map(|cards| <[Card; 5]>::try_from(cards.as_slice()).unwrap())
+        .collect::<Box<_>>();
+    let mut results = vec![Eval::WORST; hands.len()];
+
+    group.bench_function("dynamic_many", |b| {
+        b.iter(|| eval.evaluate_many(&hands, &mut results))
+    });
+
+    group.bench_function("static_many", |b| {
+        b.iter(|| static_lookup::evaluate_many(&hands, &mut results))
+    });
+
     group.finish();
 }
 
 criterion_group!(
     benches,
     bench_evaluator,
+    bench_seven_card_evaluator,
     bench_single_5card_hand_eval,
     bench_single_7card_hand_eval,
+    bench_many_7card_eval,
     bench_all_5card_eval,
 );
 
@@ -1,33 +1,180 @@
+//! With the `static_lookup` feature, this build script generates the static
+//! lookup table that is compiled into the library.
+//!
+//! Rather than duplicating the code that builds the table, the script compiles
+//! the very modules that [`LookupTable::new`] depends on, laid out under the
+//! same paths they have in the library. Only modules that depend on nothing but
+//! each other and `std` can be included here.
+
 #[cfg(feature = "static_lookup")]
-use std::{env, fs::File, io::Write, path::PathBuf};
+use std::{
+    env,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+};
 
 #[cfg(feature = "static_lookup")]
-const URL: &str =
-    "https://raw.githubusercontent.com/deus-x-mackina/poker-lookup-table/8e22032a6280eb790a13cc16b26efe4e16d1f43a/codegen.txt";
+use card::rank::Rank;
+#[cfg(feature = "static_lookup")]
+use evaluate::{lookup_table::LookupTable, meta::Meta};
+#[cfg(feature = "static_lookup")]
+use rustc_hash::FxHashMap;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    if !cfg!(feature = "static_lookup") {
-        return Ok(());
-    }
+#[cfg(feature = "static_lookup")]
+#[allow(dead_code)]
+#[path = "src/constants.rs"]
+mod constants;
 
-    let docsrs = std::env::var("DOCS_RS").unwrap_or_default();
-    if docsrs == "1" {
-        return Ok(());
-    }
+#[cfg(feature = "static_lookup")]
+#[allow(dead_code)]
+mod card {
+    pub(crate) use super::rank_module as rank;
+}
+
+#[cfg(feature = "static_lookup")]
+#[allow(dead_code)]
+#[path = "src/card/rank.rs"]
+mod rank_module;
+
+#[cfg(feature = "static_lookup")]
+#[allow(dead_code)]
+mod evaluate {
+    pub(crate) use super::{class, hand_rank, lookup_table, meta, utils};
+}
+
+#[cfg(feature = "static_lookup")]
+#[allow(dead_code)]
+#[path = "src/evaluate/class.rs"]
+mod class;
+
+#[cfg(feature = "static_lookup")]
+#[allow(dead_code)]
+#[path = "src/evaluate/hand_rank.rs"]
+mod hand_rank;
+
+#[cfg(feature = "static_lookup")]
+#[allow(dead_code)]
+#[path = "src/evaluate/lookup_table.rs"]
+mod lookup_table;
+
+#[cfg(feature = "static_lookup")]
+#[allow(dead_code)]
+#[path = "src/evaluate/meta.rs"]
+mod meta;
+
+#[cfg(feature = "static_lookup")]
+#[allow(dead_code)]
+#[path = "src/evaluate/utils/ranks.rs"]
+mod utils;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // The modules included above are tracked as part of the build script
+    println!("cargo:rerun-if-changed=build.rs");
 
     #[cfg(feature = "static_lookup")]
     {
         let path = env::var("OUT_DIR").map(PathBuf::from)?.join("codegen.rs");
-        let mut file = File::create(path)?;
-        let bytes = match reqwest::blocking::get(URL) {
-            Err(_) => panic!(
-                "You need to be connected to the internet in order to build `poker` with \
-                 `static_lookup`"
-            ),
-            Ok(response) => response.bytes()?,
-        };
-        file.write_all(&bytes)?;
+        let mut file = BufWriter::new(File::create(path)?);
+        let table = LookupTable::new();
+        writeln!(
+            file,
+            "use crate::{{evaluate::{{hand_rank::PokerHandRank, meta::Meta::*}}, Rank::*}};"
+        )?;
+        write_map(&mut file, "FLUSH_LOOKUP", &table.flush_lookup)?;
+        write_map(&mut file, "UNSUITED_LOOKUP", &table.unsuited_lookup)?;
+        write_array(
+            &mut file,
+            "DENSE_LOOKUP",
+            "i16",
+            &table.dense_lookup(),
+            |hand_rank| hand_rank.to_string(),
+        )?;
+        write_array(
+            &mut file,
+            "META_LOOKUP",
+            "crate::evaluate::meta::Meta",
+            &table.meta_lookup(),
+            |&meta| meta_expr(meta),
+        )?;
+        file.flush()?;
     }
 
     Ok(())
 }
+
+/// Write a lookup table as a `static` [`phf::Map`] with the given name.
+#[cfg(feature = "static_lookup")]
+fn write_map(out: &mut impl Write, name: &str, lookup: &FxHashMap<i32, Meta>) -> io::Result<()> {
+    // Sort the entries so that the generated code is the same every build
+    let mut entries = lookup.iter().collect::<Vec<_>>();
+    entries.sort_unstable_by_key(|&(&product, _)| product);
+
+    let mut map = phf_codegen::Map::new();
+    for (&product, &meta) in entries {
+        map.entry(product, &meta_expr(meta));
+    }
+    writeln!(
+        out,
+        "pub static {}: ::phf::Map<i32, crate::evaluate::meta::Meta> = {};",
+        name,
+        map.build()
+    )
+}
+
+/// Write a lookup table laid out as an array as a `static` with the given
+/// name, writing each element with `expr`.
+#[cfg(feature = "static_lookup")]
+fn write_array<T>(
+    out: &mut impl Write,
+    name: &str,
+    ty: &str,
+    lookup: &[T],
+    expr: impl Fn(&T) -> String,
+) -> io::Result<()> {
+    writeln!(out, "pub static {}: [{}; {}] = [", name, ty, lookup.len())?;
+    for element in lookup {
+        writeln!(out, "    {},", expr(element))?;
+    }
+    writeln!(out, "];")
+}
+
+/// Write out the expression that rebuilds `meta` in the generated code, where
+/// the variants of `Meta` and `Rank` are in scope.
+#[cfg(feature = "static_lookup")]
+fn meta_expr(meta: Meta) -> String {
+    let rank = |rank: Rank| format!("{:?}", rank);
+    let (variant, ranks) = match meta {
+        Meta::HighCard { high_rank, .. } => ("HighCard", format!("high_rank: {}", rank(high_rank))),
+        Meta::Pair { pair, .. } => ("Pair", format!("pair: {}", rank(pair))),
+        Meta::TwoPair {
+            high_pair,
+            low_pair,
+            ..
+        } => (
+            "TwoPair",
+            format!(
+                "high_pair: {}, low_pair: {}",
+                rank(high_pair),
+                rank(low_pair)
+            ),
+        ),
+        Meta::ThreeOfAKind { trips, .. } => ("ThreeOfAKind", format!("trips: {}", rank(trips))),
+        Meta::Straight { high_rank, .. } => ("Straight", format!("high_rank: {}", rank(high_rank))),
+        Meta::Flush { high_rank, .. } => ("Flush", format!("high_rank: {}", rank(high_rank))),
+        Meta::FullHouse { trips, pair, .. } => (
+            "FullHouse",
+            format!("trips: {}, pair: {}", rank(trips), rank(pair)),
+        ),
+        Meta::FourOfAKind { quads, .. } => ("FourOfAKind", format!("quads: {}", rank(quads))),
+        Meta::StraightFlush { high_rank, .. } => {
+            ("StraightFlush", format!("high_rank: {}", rank(high_rank)))
+        }
+    };
+    format!(
+        "{} {{ hand_rank: PokerHandRank({}), {} }}",
+        variant,
+        meta.hand_rank().0,
+        ranks
+    )
+}
@@ -2,8 +2,7 @@ use std::collections::HashSet;
 
 use colored::Colorize;
 use itertools::Itertools;
-use poker::{deck, Card, EvalClass, Evaluator, Rank};
-use rand::prelude::*;
+use poker::{deck::Deck, Card, EvalClass, Evaluator, Rank};
 
 #[macro_use]
 mod common;
@@ -19,7 +18,6 @@ To quit, enter "quit" or press CTRL+C.
 fn main() {
     // Clear the screen
     clear_terminal!();
-    let mut rng = thread_rng();
     let eval = Evaluator::new();
 
     println!("{}", WELCOME.bright_green().bold());
@@ -27,9 +25,7 @@ fn main() {
     let mut rl = common::editor();
 
     // Game setup
-    let mut deck = deck::shuffled().to_vec();
     let mut credits = STARTING_CREDITS;
-    let mut hand = Vec::with_capacity(5);
 
     'game: loop {
         // Get wager
@@ -39,8 +35,9 @@ fn main() {
         };
         credits -= wager;
 
-        // Deal hand and print cards, along with helper numbers
-        hand.extend(deck.drain(..5));
+        // Shuffle a fresh deck, deal hand and print cards, along with helper numbers
+        let mut deck = Deck::shuffled();
+        let mut hand = deck.draw(5).unwrap();
         let first_eval = eval.evaluate(&hand).unwrap();
         clear_terminal!();
         println!(
@@ -56,7 +53,7 @@ fn main() {
 
         // Replace swaps in hand
         if !swaps.is_empty() {
-            swap_cards(swaps, &mut deck, &mut hand, &mut rng);
+            swap_cards(swaps, &mut deck, &mut hand);
         }
 
         // Print second hand
@@ -94,22 +91,16 @@ fn main() {
             break 'game;
         }
 
-        // Cleanup
         println!();
-        deck.extend(hand.drain(..));
-        deck.shuffle(&mut rng);
 
         // Loop back
     }
 }
 
-fn swap_cards(swaps: Vec<usize>, deck: &mut Vec<Card>, hand: &mut Vec<Card>, rng: &mut ThreadRng) {
-    for &index in &swaps {
-        deck.push(hand[index]);
-    }
-    deck.shuffle(rng);
-    for (i, dealt_card) in deck.drain(..swaps.len()).enumerate() {
-        hand[swaps[i]] = dealt_card;
+fn swap_cards(swaps: Vec<usize>, deck: &mut Deck, hand: &mut [Card]) {
+    let dealt_cards = deck.draw(swaps.len()).unwrap();
+    for (index, dealt_card) in swaps.into_iter().zip(dealt_cards) {
+        hand[index] = dealt_card;
     }
 }
 
@@ -173,7 +173,6 @@ macro_rules! box_cards {
 
 #[cfg(test)]
 mod tests {
-    use crate::{box_cards, card, cards};
     #[test]
     fn box_cards_works() {
         let cards1 = &cards!(Ace of Clubs);
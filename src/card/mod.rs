@@ -52,12 +52,14 @@
 //! [`unique_integer()`]: Card::unique_integer
 
 mod macros;
+mod notation;
 pub(crate) mod rank;
+pub(crate) mod set;
 pub(crate) mod suit;
 
-use std::{
+use core::{
     cmp::Ordering,
-    convert::{TryFrom, TryInto},
+    convert::TryInto,
     fmt,
     hash::Hash,
     iter::{FromIterator, FusedIterator},
@@ -65,7 +67,12 @@ use std::{
 };
 
 #[doc(inline)]
-pub use self::{rank::Rank, suit::Suit};
+pub use self::{
+    notation::{CardNotation, Duplicates},
+    rank::Rank,
+    set::{CardSet, CardSetIter},
+    suit::Suit,
+};
 use crate::{constants::PRIMES, error::ParseCardError};
 
 /// A single playing card.
@@ -75,7 +82,8 @@ use crate::{constants::PRIMES, error::ParseCardError};
 ///   [`Card::new`]
 /// - When printed in [`Display`] mode, cards are printed to look like physical
 ///   cards.
-/// - Joker cards are not supported.
+/// - Jokers are not cards. Hands with jokers or other wild cards are made of
+///   [`WildCard`](crate::WildCard)s instead.
 ///
 /// # Example
 ///
@@ -144,18 +152,12 @@ impl Card {
     /// assert_eq!(card_one, card_two);
     /// ```
     pub fn try_from_chars(rank_char: char, suit_char: char) -> Result<Self, ParseCardError> {
-        let rank = rank_char
-            .try_into()
-            .map_err(|incorrect_char| ParseCardError::InvalidRank {
-                original_input: rank_char.to_string(),
-                incorrect_char,
-            })?;
-        let suit = suit_char
-            .try_into()
-            .map_err(|incorrect_char| ParseCardError::InvalidSuit {
-                original_input: suit_char.to_string(),
-                incorrect_char,
-            })?;
+        let rank = rank_char.try_into().map_err(|incorrect_char| {
+            ParseCardError::invalid_rank(rank_char.encode_utf8(&mut [0; 4]), None, incorrect_char)
+        })?;
+        let suit = suit_char.try_into().map_err(|incorrect_char| {
+            ParseCardError::invalid_suit(suit_char.encode_utf8(&mut [0; 4]), None, incorrect_char)
+        })?;
         Ok(Self::new(rank, suit))
     }
 
@@ -210,6 +212,35 @@ impl Card {
     /// [module level documentation]: self
     pub const fn unique_integer(self) -> i32 { self.unique_integer }
 
+    /// Obtain a unique index for this [`Card`], from 0 to 51 inclusive. Cards
+    /// are indexed in the same order that [`deck::generate`] yields them, so
+    /// lower-ranked cards have lower indices. This is the index used for the
+    /// bits of a [`CardSet`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{card, deck};
+    ///
+    /// assert_eq!(card!(Two of Clubs).index(), 0);
+    /// assert_eq!(card!(Ace of Diamonds).index(), 51);
+    /// assert!(deck::generate()
+    ///     .enumerate()
+    ///     .all(|(i, card)| usize::from(card.index()) == i));
+    /// ```
+    ///
+    /// [`deck::generate`]: crate::deck::generate
+    pub const fn index(self) -> u8 { self.rank() as u8 * 4 + self.suit() as u8 }
+
+    /// The inverse of [`Card::index`]. As this function is private, be sure to
+    /// only pass in 0 through 51 inclusive.
+    pub(crate) const fn from_index(index: u8) -> Self {
+        Self::new(
+            Rank::ALL_VARIANTS[(index / 4) as usize],
+            Suit::ALL_VARIANTS[(index % 4) as usize],
+        )
+    }
+
     /// Obtain a two-character [`String`] representation of this [`Card`]. This
     /// will be in the same format that other `Card`-producing parsing
     /// functions accept.
@@ -225,6 +256,7 @@ impl Card {
     /// let card_two = card_one_string.parse().expect("couldn't parse string");
     /// assert_eq!(card_one, card_two);
     /// ```
+    #[cfg(feature = "std")]
     pub fn rank_suit_string(self) -> String {
         let mut s = String::with_capacity(2);
         s.push(self.rank().as_char());
@@ -266,7 +298,7 @@ impl Card {
     /// ```
     ///
     /// [`try_collect`]: ParseToIter::try_collect
-    /// [`itertools`]: itertools::Itertools::try_collect
+    /// [`itertools`]: https://docs.rs/itertools/latest/itertools/trait.Itertools.html#method.try_collect
     pub fn parse_to_iter<S>(
         strings: S,
     ) -> ParseToIter<impl Iterator<Item = Result<Self, ParseCardError>>>
@@ -276,38 +308,148 @@ impl Card {
     {
         ParseToIter(strings.into_iter().map(|s| s.as_ref().parse()))
     }
+
+    /// Parse a single [`Card`] written in any of the forms that `notation`
+    /// allows, such as "10c" or "[ A♠ ]" with [`CardNotation::LENIENT`]. With
+    /// [`CardNotation::STRICT`], this is the same as [`str::parse`].
+    ///
+    /// # Errors
+    ///
+    /// This function will fail with a [`ParseCardError`] if `input` is not a
+    /// single card in one of the forms that `notation` allows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{card, Card, CardNotation};
+    ///
+    /// let card = Card::parse_with("[ A♠ ]", CardNotation::LENIENT).expect("couldn't parse card");
+    /// assert_eq!(card, card!(Ace of Spades));
+    /// assert_eq!(card.to_string(), "[ A♠ ]");
+    /// ```
+    pub fn parse_with(input: &str, notation: CardNotation) -> Result<Self, ParseCardError> {
+        notation::parse_card(input, input, None, notation)
+    }
+
+    /// Parse a whole hand of cards separated by whitespace, in any of the forms
+    /// that `notation` allows, and with or without repeated cards, as
+    /// `duplicates` says. Like [`Card::parse_to_iter`], this returns an
+    /// iterator that yields a `Result` for every card, and has a
+    /// [`try_collect`] method.
+    ///
+    /// # Errors
+    ///
+    /// The returned iterator will yield a [`ParseCardError`] for every part of
+    /// `input` that is not a card in one of the forms that `notation` allows,
+    /// and for every card that is already in the hand with
+    /// [`Duplicates::Reject`]. The error holds on to the whole of `input`,
+    /// along with the position, counted in characters, of the card or the
+    /// character that couldn't be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{cards, Card, CardNotation, Duplicates, ParseCardError};
+    ///
+    /// let hand: Vec<Card> =
+    ///     Card::parse_hand("[ A♠ ] 10h kD", CardNotation::LENIENT, Duplicates::Allow)
+    ///         .try_collect()
+    ///         .expect("couldn't parse cards");
+    /// assert_eq!(hand, cards!(Ace of Spades, Ten of Hearts, King of Diamonds));
+    ///
+    /// let result = Card::parse_hand("As Kd 1h", CardNotation::STRICT, Duplicates::Allow)
+    ///     .try_collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     result,
+    ///     Err(ParseCardError::InvalidRank {
+    ///         original_input: "As Kd 1h".into(),
+    ///         position: Some(6),
+    ///         incorrect_char: '1',
+    ///     })
+    /// );
+    /// ```
+    ///
+    /// [`try_collect`]: ParseToIter::try_collect
+    pub fn parse_hand(
+        input: &str,
+        notation: CardNotation,
+        duplicates: Duplicates,
+    ) -> ParseToIter<impl Iterator<Item = Result<Self, ParseCardError>> + '_> {
+        ParseToIter(notation::parse_cards(input, notation, duplicates, false))
+    }
+
+    /// Parse a whole hand of cards written one after another, like "AsKdQh"
+    /// or "Th9h8h", as they often are in hand histories, solver output, and
+    /// URLs. Any of the forms that `notation` allows may be used, cards may
+    /// still be separated by whitespace, and `duplicates` says whether a card
+    /// may be repeated. Like [`Card::parse_to_iter`], this
+    /// returns an iterator that yields a `Result` for every card, and has a
+    /// [`try_collect`] method.
+    ///
+    /// # Errors
+    ///
+    /// The returned iterator will yield a [`ParseCardError`] for the first
+    /// card that is not in one of the forms that `notation` allows, or that is
+    /// already in the hand with [`Duplicates::Reject`]. The error holds
+    /// on to the whole of `input`, along with the position, counted in
+    /// characters, of the card or the character that couldn't be parsed. As
+    /// there is no telling where the next card starts after that, the
+    /// iterator ends there.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{card, cards, Card, CardNotation, Duplicates, ParseCardError};
+    ///
+    /// let hand: Vec<Card> =
+    ///     Card::parse_concatenated("AsKd10h", CardNotation::LENIENT, Duplicates::Allow)
+    ///         .try_collect()
+    ///         .expect("couldn't parse cards");
+    /// assert_eq!(hand, cards!(Ace of Spades, King of Diamonds, Ten of Hearts));
+    ///
+    /// let result = Card::parse_concatenated("AsKdQx", CardNotation::STRICT, Duplicates::Allow)
+    ///     .try_collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     result,
+    ///     Err(ParseCardError::InvalidSuit {
+    ///         original_input: "AsKdQx".into(),
+    ///         position: Some(5),
+    ///         incorrect_char: 'x',
+    ///     })
+    /// );
+    ///
+    /// let result = Card::parse_concatenated("AsKdAs", CardNotation::STRICT, Duplicates::Reject)
+    ///     .try_collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     result,
+    ///     Err(ParseCardError::DuplicateCard {
+    ///         original_input: "AsKdAs".into(),
+    ///         position: 4,
+    ///         card: card!(Ace of Spades),
+    ///     })
+    /// );
+    /// ```
+    ///
+    /// [`try_collect`]: ParseToIter::try_collect
+    pub fn parse_concatenated(
+        input: &str,
+        notation: CardNotation,
+        duplicates: Duplicates,
+    ) -> ParseToIter<impl Iterator<Item = Result<Self, ParseCardError>> + '_> {
+        ParseToIter(notation::parse_cards(input, notation, duplicates, true))
+    }
 }
 
 impl FromStr for Card {
     type Err = ParseCardError;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        let mut chars = value.chars();
-        // Only parse input of two chars -- no more, no less!
-        if let (Some(rank), Some(suit), None) = (chars.next(), chars.next(), chars.next()) {
-            let rank =
-                Rank::try_from(rank).map_err(|incorrect_char| ParseCardError::InvalidRank {
-                    original_input: value.to_string(),
-                    incorrect_char,
-                })?;
-            let suit =
-                Suit::try_from(suit).map_err(|incorrect_char| ParseCardError::InvalidSuit {
-                    original_input: value.to_string(),
-                    incorrect_char,
-                })?;
-            Ok(Self::new(rank, suit))
-        } else {
-            Err(ParseCardError::InvalidLength {
-                original_input: value.to_string(),
-            })
-        }
+        Self::parse_with(value, CardNotation::STRICT)
     }
 }
 
 impl PartialOrd for Card {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.rank().partial_cmp(&other.rank())
-    }
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
 }
 
 impl Ord for Card {
@@ -330,6 +472,32 @@ impl fmt::Display for Card {
     }
 }
 
+/// Formats a [`Card`] in the two-character form of
+/// [`Card::rank_suit_string`], without allocating. The [`Debug`](fmt::Debug)
+/// form is quoted, like that of the `String`.
+#[derive(Clone, Copy)]
+pub(crate) struct RankSuit(pub(crate) Card);
+
+impl RankSuit {
+    pub(crate) fn with_str<T>(self, f: impl FnOnce(&str) -> T) -> T {
+        // Rank and suit characters are always ASCII
+        let buf = [self.0.rank().as_char() as u8, self.0.suit().as_char() as u8];
+        f(core::str::from_utf8(&buf).expect("rank and suit characters are ASCII"))
+    }
+}
+
+impl fmt::Display for RankSuit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.with_str(|s| fmt::Display::fmt(s, f))
+    }
+}
+
+impl fmt::Debug for RankSuit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.with_str(|s| fmt::Debug::fmt(s, f))
+    }
+}
+
 /// An iterator adaptor returned from [`Card::parse_to_iter`]. It doesn't do
 /// anything special, but does have a method
 /// [`try_collect`](ParseToIter::try_collect) to consolidate [`Card`]s into a
@@ -376,7 +544,7 @@ where
     /// If any item in this iterator yields an `Err` variant, that `Err` is
     /// returned.
     ///
-    /// [`itertools`]: itertools::Itertools::try_collect
+    /// [`itertools`]: https://docs.rs/itertools/latest/itertools/trait.Itertools.html#method.try_collect
     pub fn try_collect<C: FromIterator<T>>(self) -> Result<C, E> { self.0.collect() }
 }
 
@@ -393,6 +561,7 @@ mod tests {
             result.unwrap_err(),
             ParseCardError::InvalidSuit {
                 original_input: "2x".into(),
+                position: None,
                 incorrect_char: 'x'
             }
         );
@@ -403,6 +572,7 @@ mod tests {
             result.unwrap_err(),
             ParseCardError::InvalidRank {
                 original_input: "Rd".into(),
+                position: None,
                 incorrect_char: 'R'
             }
         );
@@ -412,7 +582,9 @@ mod tests {
         assert_eq!(
             result.unwrap_err(),
             ParseCardError::InvalidLength {
-                original_input: "AsX".into()
+                original_input: "AsX".into(),
+                position: None,
+                length: 3,
             }
         );
     }
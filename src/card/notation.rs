@@ -0,0 +1,454 @@
+use core::convert::TryFrom;
+
+use super::{Card, CardSet, Rank, Suit};
+use crate::error::ParseCardError;
+
+/// The card notations accepted by [`Card::parse_with`], [`Card::parse_hand`],
+/// and [`Card::parse_concatenated`], on top of the two-character form that
+/// [`Card::rank_suit_string`] returns, like "As".
+///
+/// The [`Default`] notation is [`CardNotation::STRICT`], which accepts exactly
+/// what parsing a [`Card`] with [`str::parse`] does. [`CardNotation::LENIENT`]
+/// accepts every alternative form, including the [`Display`] form of a
+/// [`Card`], so printed cards can be parsed back.
+///
+/// # Example
+///
+/// ```
+/// use poker::{card, Card, CardNotation};
+///
+/// let ten_of_clubs = card!(Ten of Clubs);
+/// assert!(Card::parse_with("10c", CardNotation::STRICT).is_err());
+/// assert_eq!(Card::parse_with("10c", CardNotation::LENIENT), Ok(ten_of_clubs));
+/// assert_eq!(Card::parse_with("tC", CardNotation::LENIENT), Ok(ten_of_clubs));
+/// assert_eq!(
+///     Card::parse_with(&ten_of_clubs.to_string(), CardNotation::LENIENT),
+///     Ok(ten_of_clubs)
+/// );
+///
+/// let symbols_only = CardNotation {
+///     suit_symbols: true,
+///     ..CardNotation::STRICT
+/// };
+/// assert_eq!(Card::parse_with("T♣", symbols_only), Ok(ten_of_clubs));
+/// assert!(Card::parse_with("tc", symbols_only).is_err());
+/// ```
+///
+/// [`Card::rank_suit_string`]: Card::rank_suit_string
+/// [`Display`]: core::fmt::Display
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CardNotation {
+    /// Whether a ten may be written as "10", like "10c", as well as "T".
+    pub ten_as_number: bool,
+    /// Whether ranks and suits may be written in either case, like "jc" or
+    /// "2D".
+    pub ignore_case: bool,
+    /// Whether suits may be written as symbols, like "A♠". Both the filled
+    /// symbols (♠♥♦♣) and the outlined ones (♤♡♢♧) are accepted.
+    pub suit_symbols: bool,
+    /// Whether a card may be wrapped in square brackets, with or without
+    /// spaces inside them, like "[ As ]". Together with `suit_symbols`, this
+    /// accepts the [`Display`](core::fmt::Display) form of a [`Card`], like
+    /// "[ A♠ ]".
+    pub brackets: bool,
+}
+
+impl CardNotation {
+    /// Every form of a card this crate knows of, like "As", "as", "AS", "A♠",
+    /// "10s", and "[ A♠ ]".
+    pub const LENIENT: Self = Self {
+        ten_as_number: true,
+        ignore_case: true,
+        suit_symbols: true,
+        brackets: true,
+    };
+    /// Only the two-character form of a card, like "As", with ranks from
+    /// "23456789TJQKA" and suits from "chsd". This is case-sensitive!
+    pub const STRICT: Self = Self {
+        ten_as_number: false,
+        ignore_case: false,
+        suit_symbols: false,
+        brackets: false,
+    };
+
+    fn rank(self, c: char) -> Option<Rank> {
+        let c = if self.ignore_case {
+            c.to_ascii_uppercase()
+        } else {
+            c
+        };
+        Rank::try_from(c).ok()
+    }
+
+    fn suit(self, c: char) -> Option<Suit> {
+        match c {
+            '\u{2660}' | '\u{2664}' if self.suit_symbols => Some(Suit::Spades), // ♠ ♤
+            '\u{2665}' | '\u{2661}' if self.suit_symbols => Some(Suit::Hearts), // ♥ ♡
+            '\u{2666}' | '\u{2662}' if self.suit_symbols => Some(Suit::Diamonds), // ♦ ♢
+            '\u{2663}' | '\u{2667}' if self.suit_symbols => Some(Suit::Clubs),  // ♣ ♧
+            _ if self.ignore_case => Suit::try_from(c.to_ascii_lowercase()).ok(),
+            _ => Suit::try_from(c).ok(),
+        }
+    }
+}
+
+impl Default for CardNotation {
+    fn default() -> Self { Self::STRICT }
+}
+
+/// Whether a hand parsed with [`Card::parse_hand`] or
+/// [`Card::parse_concatenated`] may hold the same card more than once.
+///
+/// # Example
+///
+/// ```
+/// use poker::{card, Card, CardNotation, Duplicates, ParseCardError};
+///
+/// let hand = "As Kd As";
+/// let result = Card::parse_hand(hand, CardNotation::STRICT, Duplicates::Allow)
+///     .try_collect::<Vec<_>>();
+/// assert_eq!(result.map(|cards| cards.len()), Ok(3));
+///
+/// let result = Card::parse_hand(hand, CardNotation::STRICT, Duplicates::Reject)
+///     .try_collect::<Vec<_>>();
+/// assert_eq!(
+///     result,
+///     Err(ParseCardError::DuplicateCard {
+///         original_input: hand.into(),
+///         position: 6,
+///         card: card!(Ace of Spades),
+///     })
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Duplicates {
+    /// Every card is parsed on its own, so the same card may appear any number
+    /// of times.
+    Allow,
+    /// A card that is already in the hand is reported as a
+    /// [`ParseCardError::DuplicateCard`].
+    Reject,
+}
+
+/// Parse `card` as a single card. If it is part of a hand, `position` is where
+/// it starts within `original_input`, and errors report their position within
+/// `original_input` too.
+pub(crate) fn parse_card(
+    original_input: &str,
+    card: &str,
+    position: Option<usize>,
+    notation: CardNotation,
+) -> Result<Card, ParseCardError> {
+    let (card, position) = match card.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+        Some(inner) if notation.brackets => {
+            let trimmed = inner.trim_start();
+            let padding = inner.chars().count() - trimmed.chars().count();
+            (trimmed.trim_end(), position.map(|p| p + 1 + padding))
+        }
+        _ => (card, position),
+    };
+    let invalid_length =
+        || ParseCardError::invalid_length(original_input, position, card.chars().count());
+
+    // Check the length before the characters, as `str::parse` always has
+    let (rank, rest) = match card.strip_prefix("10") {
+        Some(rest) if notation.ten_as_number => (Ok(Rank::Ten), rest),
+        _ => {
+            let mut chars = card.chars();
+            match chars.next() {
+                Some(c) => (notation.rank(c).ok_or(c), chars.as_str()),
+                None => return Err(invalid_length()),
+            }
+        }
+    };
+    let mut chars = rest.chars();
+    let suit_char = match (chars.next(), chars.next()) {
+        (Some(c), None) => c,
+        _ => return Err(invalid_length()),
+    };
+
+    let rank = rank.map_err(|incorrect_char| {
+        ParseCardError::invalid_rank(original_input, position, incorrect_char)
+    })?;
+    let suit = notation.suit(suit_char).ok_or_else(|| {
+        let suit_position = position.map(|p| p + card.chars().count() - 1);
+        ParseCardError::invalid_suit(original_input, suit_position, suit_char)
+    })?;
+    Ok(Card::new(rank, suit))
+}
+
+/// Parse every card of `input`, keeping track of where each card starts.
+///
+/// The cards may be separated by whitespace, and unless they are
+/// `concatenated`, they must be. Concatenated cards are split up by what a card
+/// can look like instead, so once one fails to parse, there is no telling
+/// where the next one starts, and parsing stops.
+pub(crate) fn parse_cards(
+    input: &str,
+    notation: CardNotation,
+    duplicates: Duplicates,
+    concatenated: bool,
+) -> impl Iterator<Item = Result<Card, ParseCardError>> + '_ {
+    let mut rest = input;
+    let mut position = 0;
+    let mut seen = CardSet::new();
+    let mut failed = false;
+    core::iter::from_fn(move || {
+        if failed {
+            return None;
+        }
+        let start = rest.find(|c: char| !c.is_whitespace())?;
+        position += rest[..start].chars().count();
+        rest = &rest[start..];
+
+        // Brackets may have spaces inside of them
+        let bracketed = match rest.find(']') {
+            Some(end) if notation.brackets && rest.starts_with('[') => Some(end + 1),
+            _ => None,
+        };
+        let end = bracketed
+            .or_else(|| {
+                if concatenated {
+                    concatenated_card_end(rest, notation)
+                } else {
+                    rest.find(char::is_whitespace)
+                }
+            })
+            .unwrap_or(rest.len());
+        let card = &rest[..end];
+        let result = parse_card(input, card, Some(position), notation).and_then(|card| {
+            if duplicates == Duplicates::Reject && !seen.insert(card) {
+                Err(ParseCardError::duplicate_card(input, position, card))
+            } else {
+                Ok(card)
+            }
+        });
+        failed = concatenated && result.is_err();
+        position += card.chars().count();
+        rest = &rest[end..];
+        Some(result)
+    })
+}
+
+/// Where the first of some concatenated cards ends: after its rank, which may
+/// be "10", and one more character for its suit. Returns `None` if the input
+/// ends before that.
+fn concatenated_card_end(input: &str, notation: CardNotation) -> Option<usize> {
+    let rank_len = match input.strip_prefix("10") {
+        Some(_) if notation.ten_as_number => 2,
+        _ => input.chars().next()?.len_utf8(),
+    };
+    let suit = input[rank_len..].chars().next()?;
+    Some(rank_len + suit.len_utf8())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{card, deck};
+
+    #[test]
+    fn strict_matches_from_str() {
+        for input in [
+            "As", "10c", "jc", "2D", "A♠", "[ As ]", "", "A", "Asx", "xs",
+        ] {
+            assert_eq!(
+                Card::parse_with(input, CardNotation::STRICT),
+                input.parse::<Card>()
+            );
+        }
+    }
+
+    #[test]
+    fn lenient_round_trips_display() {
+        for card in deck::generate() {
+            assert_eq!(
+                Card::parse_with(&card.to_string(), CardNotation::LENIENT),
+                Ok(card)
+            );
+            let lowercase = card.rank_suit_string().to_lowercase();
+            assert_eq!(
+                Card::parse_with(&lowercase, CardNotation::LENIENT),
+                Ok(card)
+            );
+        }
+        let ace_of_spades = card!(Ace of Spades);
+        for input in ["AS", "aS", "A♤", "[As]", "[  a♠ ]"] {
+            assert_eq!(
+                Card::parse_with(input, CardNotation::LENIENT),
+                Ok(ace_of_spades)
+            );
+        }
+    }
+
+    #[test]
+    fn each_option_is_separate() {
+        let ten_of_clubs = card!(Ten of Clubs);
+        let notation = |f: fn(&mut CardNotation)| {
+            let mut notation = CardNotation::STRICT;
+            f(&mut notation);
+            notation
+        };
+        let ten = notation(|n| n.ten_as_number = true);
+        let case = notation(|n| n.ignore_case = true);
+        let symbols = notation(|n| n.suit_symbols = true);
+        let brackets = notation(|n| n.brackets = true);
+        assert_eq!(Card::parse_with("10c", ten), Ok(ten_of_clubs));
+        assert!(Card::parse_with("10c", case).is_err());
+        assert_eq!(Card::parse_with("tC", case), Ok(ten_of_clubs));
+        assert!(Card::parse_with("tC", symbols).is_err());
+        assert_eq!(Card::parse_with("T♣", symbols), Ok(ten_of_clubs));
+        assert!(Card::parse_with("T♣", brackets).is_err());
+        assert_eq!(Card::parse_with("[ Tc ]", brackets), Ok(ten_of_clubs));
+        assert!(Card::parse_with("[ Tc ]", ten).is_err());
+    }
+
+    #[test]
+    fn hand_errors_have_positions() {
+        let parse = |input| {
+            Card::parse_hand(input, CardNotation::LENIENT, Duplicates::Allow)
+                .try_collect::<Vec<_>>()
+                .unwrap_err()
+        };
+        assert_eq!(
+            parse("As  10x Kd"),
+            ParseCardError::InvalidSuit {
+                original_input: "As  10x Kd".into(),
+                position: Some(6),
+                incorrect_char: 'x',
+            }
+        );
+        assert_eq!(
+            parse("[ A♠ ] [ 1♥ ]"),
+            ParseCardError::InvalidRank {
+                original_input: "[ A♠ ] [ 1♥ ]".into(),
+                position: Some(9),
+                incorrect_char: '1',
+            }
+        );
+        assert_eq!(
+            parse("As Kdd"),
+            ParseCardError::InvalidLength {
+                original_input: "As Kdd".into(),
+                position: Some(3),
+                length: 3,
+            }
+        );
+        assert_eq!(
+            parse("As Kdd").to_string(),
+            "Error parsing input 'As Kdd' as a Card: Found a card of length 3 at position 3, \
+             expected 2"
+        );
+        assert_eq!(
+            parse("[ A♠ ] [ 1♥ ]").to_string(),
+            "Error parsing input '[ A♠ ] [ 1♥ ]' as a Card: Invalid rank character '1' at \
+             position 9, expected one of [23456789TJQKA]"
+        );
+
+        // Every card is parsed, even after an error
+        let results = Card::parse_hand(" Qh x 7d ", CardNotation::STRICT, Duplicates::Allow)
+            .collect::<Vec<_>>();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[2], Ok(card!(Seven of Diamonds)));
+    }
+
+    #[test]
+    fn concatenated_cards() {
+        let deck = deck::generate().collect::<Vec<_>>();
+        let strict = deck
+            .iter()
+            .map(|card| card.rank_suit_string())
+            .collect::<String>();
+        let lenient = deck.iter().map(|card| card.to_string()).collect::<String>();
+        for (input, notation) in [
+            (strict, CardNotation::STRICT),
+            (lenient, CardNotation::LENIENT),
+        ] {
+            assert_eq!(
+                Card::parse_concatenated(&input, notation, Duplicates::Allow)
+                    .try_collect::<Vec<_>>(),
+                Ok(deck.clone())
+            );
+        }
+        assert_eq!(
+            Card::parse_concatenated(" 10h9H \t8♥", CardNotation::LENIENT, Duplicates::Allow)
+                .try_collect(),
+            Ok(vec![
+                card!(Ten of Hearts),
+                card!(Nine of Hearts),
+                card!(Eight of Hearts)
+            ])
+        );
+    }
+
+    #[test]
+    fn concatenated_errors_have_positions() {
+        let parse = |input| {
+            Card::parse_concatenated(input, CardNotation::LENIENT, Duplicates::Allow)
+                .try_collect::<Vec<_>>()
+                .unwrap_err()
+        };
+        assert_eq!(
+            parse("AsK"),
+            ParseCardError::InvalidLength {
+                original_input: "AsK".into(),
+                position: Some(2),
+                length: 1,
+            }
+        );
+        assert_eq!(
+            parse("As♠Kd"),
+            ParseCardError::InvalidRank {
+                original_input: "As♠Kd".into(),
+                position: Some(2),
+                incorrect_char: '♠',
+            }
+        );
+        assert_eq!(
+            parse("10d 1d"),
+            ParseCardError::InvalidRank {
+                original_input: "10d 1d".into(),
+                position: Some(4),
+                incorrect_char: '1',
+            }
+        );
+
+        // Parsing stops at the first error
+        let results = Card::parse_concatenated("AxKdQh", CardNotation::STRICT, Duplicates::Allow)
+            .collect::<Vec<_>>();
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn duplicates_can_be_rejected() {
+        let duplicate = ParseCardError::DuplicateCard {
+            original_input: "As Kd as".into(),
+            position: 6,
+            card: card!(Ace of Spades),
+        };
+        assert_eq!(
+            Card::parse_hand("As Kd as", CardNotation::LENIENT, Duplicates::Reject)
+                .try_collect::<Vec<_>>(),
+            Err(duplicate.clone())
+        );
+        assert_eq!(
+            duplicate.to_string(),
+            "Error parsing input 'As Kd as' as a Card: Duplicate card 'As' at position 6"
+        );
+        assert_eq!(
+            Card::parse_concatenated("AsKdas", CardNotation::LENIENT, Duplicates::Reject)
+                .try_collect::<Vec<_>>(),
+            Err(ParseCardError::DuplicateCard {
+                original_input: "AsKdas".into(),
+                position: 4,
+                card: card!(Ace of Spades),
+            })
+        );
+        assert_eq!(
+            Card::parse_concatenated("AsKdas", CardNotation::LENIENT, Duplicates::Allow)
+                .try_collect::<Vec<_>>()
+                .map(|cards| cards.len()),
+            Ok(3)
+        );
+    }
+}
@@ -0,0 +1,321 @@
+use core::{
+    fmt,
+    iter::{FromIterator, FusedIterator},
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Sub, SubAssign},
+};
+
+use super::RankSuit;
+use crate::Card;
+
+/// A set of unique [`Card`]s, backed by a single 64-bit integer where each of
+/// the 52 cards in a standard deck gets its own bit.
+///
+/// Inserting, removing, and checking for cards are all constant-time
+/// operations, and so are the set operations [`union`](CardSet::union),
+/// [`intersection`](CardSet::intersection), and
+/// [`difference`](CardSet::difference). Because a `CardSet` can never contain
+/// duplicate cards, it can be evaluated with [`Evaluator::evaluate_set`]
+/// without checking the cards for uniqueness first.
+///
+/// Iterating over a `CardSet` yields its cards in [`Card`] order, from deuces
+/// to aces. Cards of the same rank are yielded in the same order as
+/// [`deck::generate`].
+///
+/// # Example
+///
+/// ```
+/// use poker::{card, cards, CardSet};
+///
+/// let mut hand: CardSet = cards!("Kh Ah Qh").try_collect().expect("couldn't parse cards");
+/// assert_eq!(hand.len(), 3);
+/// assert!(hand.contains(card!(Ace of Hearts)));
+///
+/// // Inserting a card twice has no effect
+/// assert!(!hand.insert(card!(King of Hearts)));
+/// assert!(hand.insert(card!(Jack of Hearts)));
+///
+/// let board: CardSet = cards!("Jh Th 2c").try_collect().expect("couldn't parse cards");
+/// assert_eq!(hand.intersection(board).len(), 1);
+/// assert_eq!(hand.union(board).len(), 6);
+/// assert_eq!(
+///     hand.difference(board).iter().collect::<Vec<_>>(),
+///     cards!(Queen of Hearts, King of Hearts, Ace of Hearts)
+/// );
+/// ```
+///
+/// [`Evaluator::evaluate_set`]: crate::Evaluator::evaluate_set
+/// [`deck::generate`]: crate::deck::generate
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct CardSet {
+    bits: u64,
+}
+
+impl CardSet {
+    /// A set with no cards.
+    pub const EMPTY: Self = Self { bits: 0 };
+    /// A set containing every card in a standard 52-card deck.
+    pub const FULL: Self = Self {
+        bits: (1 << 52) - 1,
+    };
+
+    /// Create a new, empty [`CardSet`].
+    pub const fn new() -> Self { Self::EMPTY }
+
+    /// Create a [`CardSet`] from its bit representation, as returned by
+    /// [`bits`](CardSet::bits). Any bits that do not correspond to a card are
+    /// ignored.
+    pub const fn from_bits(bits: u64) -> Self {
+        Self {
+            bits: bits & Self::FULL.bits,
+        }
+    }
+
+    /// Obtain the bit representation of this set. The card with
+    /// [`Card::index`] `i` is present if bit `i` is turned on.
+    pub const fn bits(self) -> u64 { self.bits }
+
+    /// The number of cards in this set.
+    pub const fn len(self) -> usize { self.bits.count_ones() as usize }
+
+    /// Check whether this set has no cards.
+    pub const fn is_empty(self) -> bool { self.bits == 0 }
+
+    /// Check whether this set contains the given card.
+    pub const fn contains(self, card: Card) -> bool { self.bits & Self::mask(card) != 0 }
+
+    /// Add a card to this set, returning `true` if the card was not already
+    /// present.
+    pub fn insert(&mut self, card: Card) -> bool {
+        let absent = !self.contains(card);
+        self.bits |= Self::mask(card);
+        absent
+    }
+
+    /// Remove a card from this set, returning `true` if the card was present.
+    pub fn remove(&mut self, card: Card) -> bool {
+        let present = self.contains(card);
+        self.bits &= !Self::mask(card);
+        present
+    }
+
+    /// The set of cards that are in `self`, `other`, or both.
+    pub const fn union(self, other: Self) -> Self {
+        Self {
+            bits: self.bits | other.bits,
+        }
+    }
+
+    /// The set of cards that are in both `self` and `other`.
+    pub const fn intersection(self, other: Self) -> Self {
+        Self {
+            bits: self.bits & other.bits,
+        }
+    }
+
+    /// The set of cards that are in `self` but not in `other`.
+    pub const fn difference(self, other: Self) -> Self {
+        Self {
+            bits: self.bits & !other.bits,
+        }
+    }
+
+    /// Check whether `self` and `other` have no cards in common.
+    pub const fn is_disjoint(self, other: Self) -> bool { self.bits & other.bits == 0 }
+
+    /// Check whether every card in `self` is also in `other`.
+    pub const fn is_subset(self, other: Self) -> bool { self.bits & !other.bits == 0 }
+
+    /// An iterator over the cards in this set, in [`Card`] order.
+    pub const fn iter(self) -> CardSetIter { CardSetIter { bits: self.bits } }
+
+    /// Collect the cards in this set into a [`Vec`], in [`Card`] order.
+    #[cfg(feature = "std")]
+    pub fn to_vec(self) -> Vec<Card> { self.iter().collect() }
+
+    const fn mask(card: Card) -> u64 { 1 << card.index() }
+}
+
+impl fmt::Debug for CardSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter().map(RankSuit)).finish()
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output { self.union(rhs) }
+}
+
+impl BitOrAssign for CardSet {
+    fn bitor_assign(&mut self, rhs: Self) { *self = self.union(rhs) }
+}
+
+impl BitAnd for CardSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output { self.intersection(rhs) }
+}
+
+impl BitAndAssign for CardSet {
+    fn bitand_assign(&mut self, rhs: Self) { *self = self.intersection(rhs) }
+}
+
+impl Sub for CardSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output { self.difference(rhs) }
+}
+
+impl SubAssign for CardSet {
+    fn sub_assign(&mut self, rhs: Self) { *self = self.difference(rhs) }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Card> for CardSet {
+    fn extend<I: IntoIterator<Item = Card>>(&mut self, iter: I) {
+        for card in iter {
+            self.insert(card);
+        }
+    }
+}
+
+impl From<&[Card]> for CardSet {
+    fn from(cards: &[Card]) -> Self { cards.iter().copied().collect() }
+}
+
+impl<const N: usize> From<[Card; N]> for CardSet {
+    fn from(cards: [Card; N]) -> Self { cards.into_iter().collect() }
+}
+
+#[cfg(feature = "std")]
+impl From<CardSet> for Vec<Card> {
+    fn from(set: CardSet) -> Self { set.to_vec() }
+}
+
+impl IntoIterator for CardSet {
+    type IntoIter = CardSetIter;
+    type Item = Card;
+
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl IntoIterator for &CardSet {
+    type IntoIter = CardSetIter;
+    type Item = Card;
+
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+/// An iterator over the cards of a [`CardSet`], returned from
+/// [`CardSet::iter`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CardSetIter {
+    bits: u64,
+}
+
+impl Iterator for CardSetIter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
+        }
+        let index = self.bits.trailing_zeros();
+        // Turn off the lowest bit
+        self.bits &= self.bits - 1;
+        Some(Card::from_index(index as u8))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for CardSetIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
+        }
+        let index = 63 - self.bits.leading_zeros();
+        self.bits &= !(1 << index);
+        Some(Card::from_index(index as u8))
+    }
+}
+
+impl ExactSizeIterator for CardSetIter {}
+
+impl FusedIterator for CardSetIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cards, deck};
+
+    #[test]
+    fn insert_remove_contains() {
+        let mut set = CardSet::new();
+        for card in deck::generate() {
+            assert!(!set.contains(card));
+            assert!(set.insert(card));
+            assert!(!set.insert(card));
+            assert!(set.contains(card));
+        }
+        assert_eq!(set, CardSet::FULL);
+        assert_eq!(set.len(), 52);
+
+        for card in deck::generate() {
+            assert!(set.remove(card));
+            assert!(!set.remove(card));
+        }
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn iterates_in_deck_order() {
+        let cards = CardSet::FULL.iter().collect::<Vec<_>>();
+        assert_eq!(cards, deck::generate().collect::<Vec<_>>());
+        let mut reversed = CardSet::FULL.iter().rev().collect::<Vec<_>>();
+        reversed.reverse();
+        assert_eq!(reversed, cards);
+        assert!(cards.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: CardSet = cards!("As Ks Qs").try_collect().unwrap();
+        let b: CardSet = cards!("Qs Js Ts").try_collect().unwrap();
+        let queen: CardSet = cards!("Qs").try_collect().unwrap();
+
+        assert_eq!(a | b, a.union(b));
+        assert_eq!((a | b).len(), 5);
+        assert_eq!(a & b, queen);
+        assert_eq!((a - b).len(), 2);
+        assert!(!a.is_disjoint(b));
+        assert!((a - b).is_disjoint(b));
+        assert!(queen.is_subset(a));
+        assert!(!a.is_subset(b));
+        assert_eq!(CardSet::from_bits(u64::MAX), CardSet::FULL);
+    }
+
+    #[test]
+    fn conversions() {
+        let cards = cards!(Two of Clubs, Ace of Spades, Ten of Hearts);
+        let set = CardSet::from(cards);
+        assert_eq!(CardSet::from(&cards[..]), set);
+        assert_eq!(
+            set.to_vec(),
+            cards!(Two of Clubs, Ten of Hearts, Ace of Spades)
+        );
+        assert_eq!(format!("{:?}", set), r#"{"2c", "Th", "As"}"#);
+    }
+}
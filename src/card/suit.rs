@@ -1,4 +1,4 @@
-use std::{
+use core::{
     convert::TryFrom,
     fmt::{self, Write},
 };
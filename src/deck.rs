@@ -1,8 +1,16 @@
 //! A module for generating decks of cards.
+//!
+//! For one-off decks, [`generate`] and [`shuffled`] return every card at
+//! once. To deal cards out of a deck over the course of a hand, use the
+//! stateful [`Deck`] type.
 
-use itertools::Itertools;
+#[cfg(feature = "std")]
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+};
 
-use crate::{Card, Rank, Suit};
+use crate::{Card, Rank};
 
 /// Generate an iterator that will yield every card in a standard 52-card
 /// deck once. The order in which the cards are yielded is **not**
@@ -23,11 +31,24 @@ use crate::{Card, Rank, Suit};
 ///     assert!(unique_cards.insert(card));
 /// }
 /// ```
-pub fn generate() -> impl Iterator<Item = Card> {
-    Rank::ALL_VARIANTS
-        .iter()
-        .cartesian_product(Suit::ALL_VARIANTS.iter())
-        .map(|(&rank, &suit)| Card::new(rank, suit))
+pub fn generate() -> impl Iterator<Item = Card> { (0..52).map(Card::from_index) }
+
+/// Generate an iterator that will yield every card in a 36-card short deck
+/// once, as used in short-deck (6+) Hold'em. A short deck has no deuces,
+/// treys, fours or fives. The order in which the cards are yielded is **not**
+/// random.
+///
+/// # Example
+///
+/// ```
+/// use poker::{deck, Rank};
+///
+/// let deck: Vec<_> = deck::generate_short_deck().collect();
+/// assert_eq!(deck.len(), 36);
+/// assert!(deck.iter().all(|card| card.rank() >= Rank::Six));
+/// ```
+pub fn generate_short_deck() -> impl Iterator<Item = Card> {
+    generate().filter(|card| card.rank() >= Rank::Six)
 }
 
 /// Like [`generate`], but generate a shuffled deck using
@@ -48,6 +69,189 @@ where
     deck
 }
 
+/// A deck of cards that keeps track of which cards have not been dealt yet.
+///
+/// Cards are dealt from the top of the deck with [`draw`](Deck::draw),
+/// [`draw_one`](Deck::draw_one), and [`burn`](Deck::burn). Cards that are
+/// known to be out of play, like a player's hole cards in a simulation, can be
+/// taken out of the deck with [`remove`](Deck::remove).
+///
+/// With the `rand` feature, a deck can be shuffled at any time, and
+/// [`Deck::from_seed`] creates a shuffled deck that is the same every time it
+/// is created with the same seed. This makes it possible to replay a hand
+/// exactly.
+///
+/// This type needs the `std` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "rand")]
+/// # fn main() {
+/// use poker::{cards, deck::Deck, Card};
+///
+/// let mut deck = Deck::from_seed(42);
+/// let hole_cards = deck.draw(2).expect("not enough cards");
+/// deck.burn();
+/// let flop = deck.draw(3).expect("not enough cards");
+/// assert_eq!(deck.len(), 52 - 2 - 1 - 3);
+///
+/// // The same seed deals the same cards
+/// let mut replay = Deck::from_seed(42);
+/// assert_eq!(replay.draw(2), Some(hole_cards));
+///
+/// // Take known cards out of play
+/// let mut deck = Deck::new();
+/// let known: Vec<Card> = cards!("As Ad").try_collect().expect("couldn't parse cards");
+/// assert_eq!(deck.remove(&known), 2);
+/// assert_eq!(deck.len(), 50);
+/// # }
+/// # #[cfg(not(feature = "rand"))]
+/// # fn main() {}
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct Deck {
+    // The cards of the deck, with the top of the deck first. The first `top`
+    // cards have already been dealt, so that dealing doesn't shift the rest.
+    cards: Vec<Card>,
+    top: usize,
+}
+
+#[cfg(feature = "std")]
+impl Deck {
+    /// Create a new, unshuffled deck with all 52 cards, in the same order as
+    /// [`generate`].
+    pub fn new() -> Self {
+        Self {
+            cards: generate().collect(),
+            top: 0,
+        }
+    }
+
+    /// Create a new, unshuffled short deck with all 36 cards, in the same order
+    /// as [`generate_short_deck`]. Shuffle it with
+    /// [`shuffle`](Deck::shuffle) before dealing from it.
+    pub fn short_deck() -> Self {
+        Self {
+            cards: generate_short_deck().collect(),
+            top: 0,
+        }
+    }
+
+    /// Create a new, shuffled deck using [`rand`].
+    #[cfg(feature = "rand")]
+    pub fn shuffled() -> Self { Self::shuffled_with(&mut rand::thread_rng()) }
+
+    /// Create a new deck shuffled by anything that implements
+    /// [`rand::Rng`].
+    #[cfg(feature = "rand")]
+    pub fn shuffled_with<R>(rng: &mut R) -> Self
+    where
+        R: rand::Rng + ?Sized,
+    {
+        let mut deck = Self::new();
+        deck.shuffle_with(rng);
+        deck
+    }
+
+    /// Create a new deck shuffled deterministically from `seed`. The same seed
+    /// always yields the same order of cards, on any platform.
+    #[cfg(feature = "rand")]
+    pub fn from_seed(seed: u64) -> Self {
+        use rand::SeedableRng;
+        Self::shuffled_with(&mut rand_chacha::ChaCha8Rng::seed_from_u64(seed))
+    }
+
+    /// Deal `n` cards from the top of the deck. If fewer than `n` cards
+    /// remain, returns `None` and leaves the deck untouched.
+    pub fn draw(&mut self, n: usize) -> Option<Vec<Card>> {
+        if n > self.len() {
+            return None;
+        }
+        let drawn = self.cards[self.top..self.top + n].to_vec();
+        self.top += n;
+        Some(drawn)
+    }
+
+    /// Deal a single card from the top of the deck, or return `None` if the
+    /// deck is empty.
+    pub fn draw_one(&mut self) -> Option<Card> {
+        let card = *self.cards.get(self.top)?;
+        self.top += 1;
+        Some(card)
+    }
+
+    /// Discard the top card of the deck, returning it if the deck was not
+    /// empty.
+    pub fn burn(&mut self) -> Option<Card> { self.draw_one() }
+
+    /// Take the given cards out of the deck, wherever they are. Cards that
+    /// have already been dealt or removed are ignored. Returns the number of
+    /// cards that were removed.
+    pub fn remove<C: AsRef<[Card]>>(&mut self, cards: C) -> usize {
+        let cards = cards.as_ref();
+        self.cards.drain(..self.top);
+        self.top = 0;
+        let before = self.cards.len();
+        self.cards.retain(|card| !cards.contains(card));
+        before - self.cards.len()
+    }
+
+    /// The cards that have not been dealt yet, with the top of the deck first.
+    pub fn remaining(&self) -> &[Card] { &self.cards[self.top..] }
+
+    /// The number of cards that have not been dealt yet.
+    pub fn len(&self) -> usize { self.cards.len() - self.top }
+
+    /// Check whether every card has been dealt.
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Shuffle the cards that have not been dealt yet using [`rand`].
+    #[cfg(feature = "rand")]
+    pub fn shuffle(&mut self) { self.shuffle_with(&mut rand::thread_rng()) }
+
+    /// Shuffle the cards that have not been dealt yet using anything that
+    /// implements [`rand::Rng`].
+    #[cfg(feature = "rand")]
+    pub fn shuffle_with<R>(&mut self, rng: &mut R)
+    where
+        R: rand::Rng + ?Sized,
+    {
+        use rand::prelude::*;
+        self.cards[self.top..].shuffle(rng);
+    }
+}
+
+// Decks are compared and hashed by their undealt cards alone, however many
+// cards were dealt to get there.
+#[cfg(feature = "std")]
+impl PartialEq for Deck {
+    fn eq(&self, other: &Self) -> bool { self.remaining() == other.remaining() }
+}
+
+#[cfg(feature = "std")]
+impl Eq for Deck {}
+
+#[cfg(feature = "std")]
+impl Hash for Deck {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.remaining().hash(state) }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for Deck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Deck")
+            .field("remaining", &self.remaining())
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for Deck {
+    fn default() -> Self { Self::new() }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::{HashMap, HashSet};
@@ -84,9 +288,86 @@ mod tests {
         assert_eq!(generate().count(), 52);
     }
 
+    #[test]
+    fn short_deck_has_no_low_cards() {
+        let deck = Deck::short_deck();
+        assert_eq!(deck.len(), 36);
+        assert_eq!(deck.remaining(), generate_short_deck().collect::<Vec<_>>());
+        assert!(generate()
+            .filter(|card| card.rank() < Rank::Six)
+            .all(|card| !deck.remaining().contains(&card)));
+    }
+
     #[test]
     #[cfg(feature = "rand")]
     fn generate_shuffled_deck_is_52_cards() {
         assert_eq!(shuffled().len(), 52);
     }
+
+    #[test]
+    fn deck_draw_burn_remove() {
+        let mut deck = Deck::new();
+        assert_eq!(deck.remaining(), generate().collect::<Vec<_>>());
+
+        let drawn = deck.draw(5).unwrap();
+        assert_eq!(drawn, generate().take(5).collect::<Vec<_>>());
+        assert_eq!(deck.burn(), generate().nth(5));
+        assert_eq!(deck.len(), 46);
+
+        // Removing cards that were already dealt has no effect
+        assert_eq!(deck.remove(&drawn), 0);
+        let last_two = &generate().collect::<Vec<_>>()[50..];
+        assert_eq!(deck.remove(last_two), 2);
+        assert_eq!(deck.len(), 44);
+
+        assert_eq!(deck.draw(45), None);
+        assert_eq!(deck.len(), 44);
+        assert_eq!(deck.draw(44).map(|cards| cards.len()), Some(44));
+        assert!(deck.is_empty());
+        assert_eq!(deck.draw_one(), None);
+        assert_eq!(deck.burn(), None);
+    }
+
+    #[test]
+    fn decks_compare_by_remaining_cards() {
+        let mut dealt = Deck::new();
+        dealt.draw(2).unwrap();
+        let mut removed = Deck::new();
+        let first_two = generate().take(2).collect::<Vec<_>>();
+        assert_eq!(removed.remove(first_two), 2);
+        assert_eq!(dealt, removed);
+        assert_eq!(dealt.clone().draw_one(), removed.draw_one());
+        assert_ne!(dealt, removed);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn deck_from_seed_is_deterministic() {
+        assert_eq!(Deck::from_seed(7), Deck::from_seed(7));
+        assert_ne!(Deck::from_seed(7), Deck::from_seed(8));
+
+        let mut deck = Deck::from_seed(7);
+        let all: HashSet<_> = deck.draw(52).unwrap().into_iter().collect();
+        assert_eq!(all.len(), 52);
+
+        // Guard against the seeded order changing between releases
+        let expected = Card::parse_to_iter(["6c", "9s", "7h", "2c", "Ad"])
+            .try_collect::<Vec<_>>()
+            .unwrap();
+        assert_eq!(Deck::from_seed(42).draw(5), Some(expected));
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn deck_shuffle_keeps_remaining_cards() {
+        let mut deck = Deck::from_seed(1);
+        let dealt = deck.draw(10).unwrap();
+        let mut before = deck.remaining().to_vec();
+        deck.shuffle();
+        let mut after = deck.remaining().to_vec();
+        assert!(after.iter().all(|card| !dealt.contains(card)));
+        before.sort_by_key(|card| card.index());
+        after.sort_by_key(|card| card.index());
+        assert_eq!(before, after);
+    }
 }
@@ -0,0 +1,1318 @@
+//! Calculate how often Texas Holdem hands win, tie, and lose against one
+//! another.
+//!
+//! Equity is a player's share of the pot, averaged over every way the board
+//! could be completed. A player who wins outright takes the whole pot, and
+//! players who tie split it evenly. The [`exact`] function finds each player's
+//! equity by evaluating every possible runout, so the results are exact and
+//! always the same for the same input.
+//!
+//! When there are too many runouts to evaluate them all, such as multiway
+//! preflop, [`monte_carlo`] (available with the `rand` feature) estimates each
+//! player's equity from randomly sampled runouts instead, along with the
+//! standard error of each estimate.
+//!
+//! Equity can also be calculated between weighted [`Range`]s of hands with
+//! [`exact_ranges`] and [`monte_carlo_ranges`], where every way of dealing one
+//! combo from each range is considered in proportion to the combos' weights.
+//!
+//! With the `rayon` feature, [`par_exact`] and [`par_exact_ranges`] split the
+//! runouts between threads. They return exactly the same results as their
+//! single-threaded counterparts, however many threads there are.
+//!
+//! ```
+//! # fn main() {
+//! #     if run().is_err() { std::process::exit(1); }
+//! # }
+//! #
+//! # fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! use poker::{cards, equity, Card, Evaluator};
+//!
+//! let eval = Evaluator::new();
+//! let players = [cards!(Ace of Spades, Ace of Hearts), cards!(King of Clubs, King of Diamonds)];
+//! let board: Vec<Card> = cards!("Kh 7d 2c").try_collect()?;
+//!
+//! let results = equity::exact(&eval, &players, &board, &[])?;
+//! assert!(results[1].equity() > 0.9);
+//! assert_eq!(results[0].wins() + results[0].ties() + results[0].losses(), results[0].total());
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+    evaluate::utils,
+    range::{self, COMBOS},
+    Card, CardSet, Eval, EvalError, Evaluator, Range,
+};
+
+/// The outcome of an equity calculation for a single player.
+///
+/// The counts returned by [`wins`](Equity::wins), [`ties`](Equity::ties), and
+/// [`losses`](Equity::losses) always add up to [`total`](Equity::total), the
+/// number of runouts that were considered.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Equity {
+    wins: u64,
+    ties: u64,
+    losses: u64,
+    // The sum of the player's share of the pot over every runout
+    share: f64,
+}
+
+impl Equity {
+    /// The number of runouts where this player won the whole pot.
+    pub const fn wins(self) -> u64 { self.wins }
+
+    /// The number of runouts where this player split the pot with at least
+    /// one other player.
+    pub const fn ties(self) -> u64 { self.ties }
+
+    /// The number of runouts where this player did not win any of the pot.
+    pub const fn losses(self) -> u64 { self.losses }
+
+    /// The total number of runouts considered.
+    pub const fn total(self) -> u64 { self.wins + self.ties + self.losses }
+
+    /// The fraction of runouts where this player won the whole pot.
+    pub fn win_fraction(self) -> f64 { self.wins as f64 / self.total() as f64 }
+
+    /// The fraction of runouts where this player split the pot.
+    pub fn tie_fraction(self) -> f64 { self.ties as f64 / self.total() as f64 }
+
+    /// The fraction of runouts where this player did not win any of the pot.
+    pub fn loss_fraction(self) -> f64 { self.losses as f64 / self.total() as f64 }
+
+    /// The average share of the pot this player wins, from 0 to 1. Outright
+    /// wins count fully, and a pot split `n` ways counts as `1 / n` of a win.
+    pub fn equity(self) -> f64 { self.share / self.total() as f64 }
+
+    /// The outcome of a heads-up matchup from its counts, where every tie
+    /// splits the pot two ways.
+    pub(crate) fn heads_up(wins: u64, ties: u64, total: u64) -> Self {
+        Self {
+            wins,
+            ties,
+            losses: total - wins - ties,
+            share: wins as f64 + ties as f64 / 2.0,
+        }
+    }
+
+    /// Record the outcome of one runout, where this player was one of
+    /// `winners` players with the best hand, or lost if `winners` is zero.
+    fn record(&mut self, winners: usize) {
+        match winners {
+            0 => self.losses += 1,
+            1 => {
+                self.wins += 1;
+                self.share += 1.0;
+            }
+            _ => {
+                self.ties += 1;
+                self.share += 1.0 / winners as f64;
+            }
+        }
+    }
+
+    /// Add the outcomes of another set of runouts for the same player.
+    fn merge(&mut self, other: Self) {
+        self.wins += other.wins;
+        self.ties += other.ties;
+        self.losses += other.losses;
+        self.share += other.share;
+    }
+}
+
+/// Calculate the exact equity of each player's hole cards by evaluating every
+/// possible way to complete the board.
+///
+/// `board` may hold anywhere from zero to five cards. Any cards in `dead`,
+/// such as folded or exposed cards, will not be dealt to the board. The
+/// results are in the same order as `hole_cards`.
+///
+/// # Errors
+///
+/// This function will fail if the board has more than five cards, if there are
+/// no players or not enough cards left to complete the board, or if the same
+/// card appears more than once among the hole cards, board, and dead cards. See
+/// [`EvalError`] for more.
+///
+/// # Performance
+///
+/// The number of runouts grows quickly as the board gets smaller. Preflop,
+/// heads-up, there are 1,712,304 runouts to evaluate, which takes a moment
+/// even in an optimized build.
+///
+/// # Example
+///
+/// ```
+/// use poker::{cards, equity, Card, Evaluator};
+///
+/// let eval = Evaluator::new();
+/// let players = [
+///     cards!(Ace of Hearts, King of Hearts),
+///     cards!(Queen of Spades, Queen of Clubs),
+/// ];
+/// let board: Vec<Card> = cards!("Qh 7h 2d 3c").try_collect().expect("couldn't parse cards");
+///
+/// let results = equity::exact(&eval, &players, &board, &[]).expect("couldn't calculate equity");
+/// // Any heart that doesn't pair the board gives ace-king a winning flush
+/// assert_eq!(results[0].wins(), 7);
+/// assert_eq!(results[1].wins(), 37);
+/// assert_eq!(results[0].total(), 44);
+/// ```
+pub fn exact(
+    evaluator: &Evaluator,
+    hole_cards: &[[Card; 2]],
+    board: &[Card],
+    dead: &[Card],
+) -> Result<Vec<Equity>, EvalError> {
+    exact_with(evaluator, hole_cards, board, dead, false)
+}
+
+/// Like [`exact`], but split the runouts between the threads of the current
+/// [rayon] thread pool. The results are exactly the same as those of
+/// [`exact`], no matter how many threads there are. This function needs the
+/// `rayon` feature.
+///
+/// # Errors
+///
+/// This function will fail for the same reasons as [`exact`]. See
+/// [`EvalError`] for more.
+///
+/// # Example
+///
+/// ```
+/// use poker::{cards, equity, Card, Evaluator};
+///
+/// let eval = Evaluator::new();
+/// let players = [
+///     cards!(Ace of Spades, Ace of Hearts),
+///     cards!(King of Clubs, King of Diamonds),
+///     cards!(Seven of Hearts, Eight of Hearts),
+/// ];
+/// let board: Vec<Card> = cards!("9h 6c 2h").try_collect().expect("couldn't parse cards");
+///
+/// let results =
+///     equity::par_exact(&eval, &players, &board, &[]).expect("couldn't calculate equity");
+/// assert_eq!(results, equity::exact(&eval, &players, &board, &[]).unwrap());
+/// assert_eq!(results[0].total(), 903);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_exact(
+    evaluator: &Evaluator,
+    hole_cards: &[[Card; 2]],
+    board: &[Card],
+    dead: &[Card],
+) -> Result<Vec<Equity>, EvalError> {
+    exact_with(evaluator, hole_cards, board, dead, true)
+}
+
+fn exact_with(
+    evaluator: &Evaluator,
+    hole_cards: &[[Card; 2]],
+    board: &[Card],
+    dead: &[Card],
+    parallel: bool,
+) -> Result<Vec<Equity>, EvalError> {
+    let deck = remaining_deck(hole_cards, board, dead)?.to_vec();
+    let parts = fold_runouts(
+        &deck,
+        5 - board.len(),
+        parallel,
+        || {
+            let results = vec![Equity::default(); hole_cards.len()];
+            (results, Vec::with_capacity(hole_cards.len()))
+        },
+        |(results, evals), runout| {
+            let full_board = complete_board(board, runout);
+            showdown(evaluator, hole_cards, &full_board, evals);
+            record_showdown(evals, results);
+        },
+    );
+
+    let mut results = vec![Equity::default(); hole_cards.len()];
+    for (part, _) in parts {
+        for (result, other) in results.iter_mut().zip(part) {
+            result.merge(other);
+        }
+    }
+    Ok(results)
+}
+
+/// How many runouts [`monte_carlo`] should sample before it stops.
+#[cfg(feature = "rand")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Budget {
+    /// Sample exactly this many runouts.
+    Iterations(u64),
+    /// Keep sampling runouts until the standard error of every player's
+    /// equity estimate is at most `target`, or until `max_iterations` runouts
+    /// have been sampled, whichever comes first.
+    StandardError {
+        /// The largest acceptable standard error.
+        target: f64,
+        /// The most runouts to sample, in case `target` is not reached.
+        max_iterations: u64,
+    },
+}
+
+#[cfg(feature = "rand")]
+impl Budget {
+    /// The target standard error, if any, and the most runouts to sample.
+    /// Fails if no runouts may be sampled at all, since there would be nothing
+    /// to estimate from.
+    fn limits(self) -> Result<(Option<f64>, u64), EvalError> {
+        let (target, max_iterations) = match self {
+            Self::Iterations(iterations) => (None, iterations),
+            Self::StandardError {
+                target,
+                max_iterations,
+            } => (Some(target), max_iterations),
+        };
+        if max_iterations == 0 {
+            Err(EvalError::EmptyBudget)
+        } else {
+            Ok((target, max_iterations))
+        }
+    }
+}
+
+/// An estimate of a single player's equity, returned from [`monte_carlo`].
+#[cfg(feature = "rand")]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Estimate {
+    outcome: Equity,
+    // The sum of the square of the player's share of the pot over every runout
+    share_squares: f64,
+}
+
+#[cfg(feature = "rand")]
+impl Estimate {
+    /// The wins, ties, and losses over the runouts that were sampled.
+    pub const fn outcome(self) -> Equity { self.outcome }
+
+    /// The estimated average share of the pot this player wins, from 0 to 1.
+    pub fn equity(self) -> f64 { self.outcome.equity() }
+
+    /// The standard error of the [`equity`](Estimate::equity) estimate. The
+    /// true equity lies within two standard errors of the estimate about 95%
+    /// of the time.
+    pub fn standard_error(self) -> f64 {
+        let total = self.outcome.total() as f64;
+        let mean = self.equity();
+        let variance = (self.share_squares / total - mean * mean).max(0.0);
+        (variance / total).sqrt()
+    }
+
+    fn record(&mut self, winners: usize) {
+        self.outcome.record(winners);
+        if winners > 0 {
+            let share = 1.0 / winners as f64;
+            self.share_squares += share * share;
+        }
+    }
+}
+
+/// Estimate the equity of each player's hole cards by sampling random ways to
+/// complete the board.
+///
+/// The arguments are the same as for [`exact`], plus a [`Budget`] that says
+/// when to stop sampling and anything that implements [`rand::Rng`] to sample
+/// with. Using a seeded random number generator makes the results
+/// reproducible: the same seed and inputs always give the same estimates.
+///
+/// # Errors
+///
+/// This function will fail for the same reasons as [`exact`], or if the
+/// budget allows no runouts to be sampled. See [`EvalError`] for more.
+///
+/// # Example
+///
+/// ```
+/// use poker::{
+///     cards,
+///     equity::{self, Budget},
+///     Evaluator,
+/// };
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let eval = Evaluator::new();
+/// let players = [
+///     cards!(Ace of Spades, Ace of Hearts),
+///     cards!(King of Clubs, King of Diamonds),
+///     cards!(Seven of Spades, Six of Spades),
+/// ];
+/// let budget = Budget::StandardError {
+///     target: 0.005,
+///     max_iterations: 100_000,
+/// };
+///
+/// let mut rng = StdRng::seed_from_u64(1);
+/// let results = equity::monte_carlo(&eval, &players, &[], &[], budget, &mut rng)
+///     .expect("couldn't estimate equity");
+/// assert!(results.iter().all(|result| result.standard_error() <= 0.005));
+/// assert!(results[0].equity() > results[1].equity());
+///
+/// // The same seed gives the same estimates
+/// let mut rng = StdRng::seed_from_u64(1);
+/// let again = equity::monte_carlo(&eval, &players, &[], &[], budget, &mut rng)
+///     .expect("couldn't estimate equity");
+/// assert_eq!(results, again);
+/// ```
+#[cfg(feature = "rand")]
+pub fn monte_carlo<R>(
+    evaluator: &Evaluator,
+    hole_cards: &[[Card; 2]],
+    board: &[Card],
+    dead: &[Card],
+    budget: Budget,
+    rng: &mut R,
+) -> Result<Vec<Estimate>, EvalError>
+where
+    R: rand::Rng + ?Sized,
+{
+    use rand::seq::SliceRandom;
+
+    // How often to check whether the target standard error has been reached
+    const CHECK_INTERVAL: u64 = 1000;
+
+    let mut deck = remaining_deck(hole_cards, board, dead)?.to_vec();
+    let (target, max_iterations) = budget.limits()?;
+
+    let needed = 5 - board.len();
+    let mut results = vec![Estimate::default(); hole_cards.len()];
+    let mut evals = Vec::with_capacity(hole_cards.len());
+    for iteration in 1..=max_iterations {
+        let (runout, _) = deck.partial_shuffle(rng, needed);
+        let full_board = complete_board(board, runout);
+        showdown(evaluator, hole_cards, &full_board, &mut evals);
+        let (best, winners) = best_hand(&evals);
+        for (&eval, result) in evals.iter().zip(&mut results) {
+            result.record(if eval == best { winners } else { 0 });
+        }
+
+        if let Some(target) = target {
+            if iteration % CHECK_INTERVAL == 0
+                && results
+                    .iter()
+                    .all(|result| result.standard_error() <= target)
+            {
+                break;
+            }
+        }
+    }
+    Ok(results)
+}
+
+/// The outcome of a range-vs-range equity calculation for a single range,
+/// returned from [`exact_ranges`].
+///
+/// Every matchup of one combo from each range counts in proportion to the
+/// product of the combos' weights, so each fraction is a weighted average over
+/// every matchup and runout.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct RangeEquity {
+    wins: f64,
+    ties: f64,
+    losses: f64,
+    // The weighted sum of the range's share of the pot over every runout
+    share: f64,
+}
+
+impl RangeEquity {
+    /// The weighted fraction of runouts where this range won the whole pot.
+    pub fn win_fraction(self) -> f64 { self.wins / self.total() }
+
+    /// The weighted fraction of runouts where this range split the pot.
+    pub fn tie_fraction(self) -> f64 { self.ties / self.total() }
+
+    /// The weighted fraction of runouts where this range did not win any of
+    /// the pot.
+    pub fn loss_fraction(self) -> f64 { self.losses / self.total() }
+
+    /// The average share of the pot this range wins, from 0 to 1.
+    pub fn equity(self) -> f64 { self.share / self.total() }
+
+    fn total(self) -> f64 { self.wins + self.ties + self.losses }
+
+    fn record(&mut self, winners: usize, weight: f64) {
+        match winners {
+            0 => self.losses += weight,
+            1 => {
+                self.wins += weight;
+                self.share += weight;
+            }
+            _ => {
+                self.ties += weight;
+                self.share += weight / winners as f64;
+            }
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.wins += other.wins;
+        self.ties += other.ties;
+        self.losses += other.losses;
+        self.share += other.share;
+    }
+}
+
+/// Calculate the exact equity of each range by evaluating every matchup of
+/// one combo from each range on every possible way to complete the board.
+///
+/// Combos that share a card with the board, with `dead`, or with a combo
+/// dealt to another range in the same matchup are never dealt. Each combo is
+/// evaluated only once per complete board, no matter how many matchups it
+/// appears in. The results are in the same order as `ranges`.
+///
+/// # Errors
+///
+/// This function will fail if the board has more than five cards, if the same
+/// card appears more than once among the board and dead cards, if a range has
+/// no combos left once the board and dead cards are removed, if there are no
+/// ranges, if there aren't enough cards left to deal every range in and
+/// complete the board, or if there is no way to deal a combo from every range
+/// at once without them sharing a card. See [`EvalError`] for more.
+///
+/// # Performance
+///
+/// The work done grows with the number of runouts multiplied by the number of
+/// matchups, so this function is best suited to flop, turn, and river boards.
+/// Use [`monte_carlo_ranges`] for larger problems.
+///
+/// # Example
+///
+/// ```
+/// use poker::{equity, Card, Evaluator, Range};
+///
+/// let eval = Evaluator::new();
+/// let ranges: Vec<Range> = ["AhKh", "QQ, JJ:0.5"]
+///     .iter()
+///     .map(|range| range.parse().expect("couldn't parse range"))
+///     .collect();
+/// let board: Vec<Card> = Card::parse_to_iter("Qh 7h 2d 3c".split_whitespace())
+///     .try_collect()
+///     .expect("couldn't parse cards");
+///
+/// let results =
+///     equity::exact_ranges(&eval, &ranges, &board, &[]).expect("couldn't calculate equity");
+/// let total: f64 = results.iter().map(|result| result.equity()).sum();
+/// assert!((total - 1.0).abs() < 1e-9);
+/// // The flush draw is worse off against sets than against jacks
+/// assert!(results[0].equity() < 0.5);
+/// ```
+pub fn exact_ranges(
+    evaluator: &Evaluator,
+    ranges: &[Range],
+    board: &[Card],
+    dead: &[Card],
+) -> Result<Vec<RangeEquity>, EvalError> {
+    exact_ranges_with(evaluator, ranges, board, dead, false)
+}
+
+/// Like [`exact_ranges`], but split the runouts between the threads of the
+/// current [rayon] thread pool. The results are exactly the same as those of
+/// [`exact_ranges`], no matter how many threads there are. This function needs
+/// the `rayon` feature.
+///
+/// # Errors
+///
+/// This function will fail for the same reasons as [`exact_ranges`]. See
+/// [`EvalError`] for more.
+#[cfg(feature = "rayon")]
+pub fn par_exact_ranges(
+    evaluator: &Evaluator,
+    ranges: &[Range],
+    board: &[Card],
+    dead: &[Card],
+) -> Result<Vec<RangeEquity>, EvalError> {
+    exact_ranges_with(evaluator, ranges, board, dead, true)
+}
+
+fn exact_ranges_with(
+    evaluator: &Evaluator,
+    ranges: &[Range],
+    board: &[Card],
+    dead: &[Card],
+    parallel: bool,
+) -> Result<Vec<RangeEquity>, EvalError> {
+    let (known, live) = live_combos(ranges, board, dead)?;
+    let deck = (CardSet::FULL - known).to_vec();
+
+    // Every combo that appears in at least one range, evaluated once per board
+    let mut distinct = live.iter().flatten().copied().collect::<Vec<_>>();
+    distinct.sort_unstable_by_key(|combo| combo.index);
+    distinct.dedup_by_key(|combo| combo.index);
+
+    let parts = fold_runouts(
+        &deck,
+        5 - board.len(),
+        parallel,
+        || RangeShowdowns {
+            results: vec![RangeEquity::default(); ranges.len()],
+            cache: vec![Eval::WORST; COMBOS],
+            chosen: Vec::with_capacity(ranges.len()),
+            evals: Vec::with_capacity(ranges.len()),
+        },
+        |state, runout| {
+            let full_board = complete_board(board, runout);
+            let board_set = CardSet::from(full_board);
+            for combo in &distinct {
+                if board_set.is_disjoint(combo.set) {
+                    state.cache[combo.index] = evaluate_combo(evaluator, &full_board, combo.cards);
+                }
+            }
+            let RangeShowdowns {
+                results,
+                cache,
+                chosen,
+                evals,
+            } = state;
+            for_each_matchup(&live, board_set, chosen, 1.0, &mut |matchup, weight| {
+                evals.clear();
+                evals.extend(matchup.iter().map(|combo| cache[combo.index]));
+                let (best, winners) = best_hand(evals);
+                for (&eval, result) in evals.iter().zip(results.iter_mut()) {
+                    result.record(if eval == best { winners } else { 0 }, weight);
+                }
+            });
+        },
+    );
+
+    let mut results = vec![RangeEquity::default(); ranges.len()];
+    for part in parts {
+        for (result, other) in results.iter_mut().zip(part.results) {
+            result.merge(other);
+        }
+    }
+    Ok(results)
+}
+
+/// The working state of [`exact_ranges`] over part of the runouts.
+struct RangeShowdowns {
+    results: Vec<RangeEquity>,
+    // The evaluation of every distinct combo on the current board
+    cache: Vec<Eval>,
+    chosen: Vec<LiveCombo>,
+    evals: Vec<Eval>,
+}
+
+/// Estimate the equity of each range by sampling random matchups and ways to
+/// complete the board.
+///
+/// Each sample deals one combo from each range, chosen in proportion to the
+/// combos' weights, and then completes the board from the remaining cards.
+/// Samples where two ranges are dealt the same card are discarded and drawn
+/// again, so card removal between the ranges is accounted for. The arguments
+/// are the same as for [`exact_ranges`], plus a [`Budget`] and a random
+/// number generator as for [`monte_carlo`].
+///
+/// # Errors
+///
+/// This function will fail for the same reasons as [`exact_ranges`], or if
+/// the budget allows no runouts to be sampled. See [`EvalError`] for more.
+///
+/// # Example
+///
+/// ```
+/// use poker::{
+///     equity::{self, Budget},
+///     Evaluator, Range,
+/// };
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let eval = Evaluator::new();
+/// let ranges: Vec<Range> = ["QQ+, AK", "22+, A2s+, KTs+, QTs+, JTs, ATo+"]
+///     .iter()
+///     .map(|range| range.parse().expect("couldn't parse range"))
+///     .collect();
+///
+/// let mut rng = StdRng::seed_from_u64(1);
+/// let results = equity::monte_carlo_ranges(
+///     &eval,
+///     &ranges,
+///     &[],
+///     &[],
+///     Budget::Iterations(20_000),
+///     &mut rng,
+/// )
+/// .expect("couldn't estimate equity");
+/// assert!(results[0].equity() > 0.55);
+/// ```
+#[cfg(feature = "rand")]
+pub fn monte_carlo_ranges<R>(
+    evaluator: &Evaluator,
+    ranges: &[Range],
+    board: &[Card],
+    dead: &[Card],
+    budget: Budget,
+    rng: &mut R,
+) -> Result<Vec<Estimate>, EvalError>
+where
+    R: rand::Rng + ?Sized,
+{
+    const CHECK_INTERVAL: u64 = 1000;
+
+    let (known, live) = live_combos(ranges, board, dead)?;
+    let deck = (CardSet::FULL - known).to_vec();
+    let cumulative_weights = live
+        .iter()
+        .map(|combos| {
+            combos
+                .iter()
+                .scan(0.0, |total, combo| {
+                    *total += combo.weight;
+                    Some(*total)
+                })
+                .collect::<Vec<f64>>()
+        })
+        .collect::<Vec<_>>();
+    let (target, max_iterations) = budget.limits()?;
+
+    let mut results = vec![Estimate::default(); ranges.len()];
+    let mut chosen = Vec::with_capacity(ranges.len());
+    let mut runout = Vec::with_capacity(5);
+    let mut evals = Vec::with_capacity(ranges.len());
+    for iteration in 1..=max_iterations {
+        // Deal one combo from each range, starting over on any conflict
+        let mut used;
+        'deal: loop {
+            used = known;
+            chosen.clear();
+            for (combos, cumulative) in live.iter().zip(&cumulative_weights) {
+                let total = cumulative[cumulative.len() - 1];
+                let point = rng.gen::<f64>() * total;
+                let position = cumulative
+                    .partition_point(|&weight| weight <= point)
+                    .min(combos.len() - 1);
+                let combo = combos[position];
+                if !used.is_disjoint(combo.set) {
+                    continue 'deal;
+                }
+                used |= combo.set;
+                chosen.push(combo);
+            }
+            break;
+        }
+
+        runout.clear();
+        while board.len() + runout.len() < 5 {
+            let card = deck[rng.gen_range(0..deck.len())];
+            if used.insert(card) {
+                runout.push(card);
+            }
+        }
+        let full_board = complete_board(board, &runout);
+
+        evals.clear();
+        evals.extend(
+            chosen
+                .iter()
+                .map(|combo| evaluate_combo(evaluator, &full_board, combo.cards)),
+        );
+        let (best, winners) = best_hand(&evals);
+        for (&eval, result) in evals.iter().zip(&mut results) {
+            result.record(if eval == best { winners } else { 0 });
+        }
+
+        if let Some(target) = target {
+            if iteration % CHECK_INTERVAL == 0
+                && results
+                    .iter()
+                    .all(|result| result.standard_error() <= target)
+            {
+                break;
+            }
+        }
+    }
+    Ok(results)
+}
+
+/// A combo from a range that can still be dealt, given the board and dead
+/// cards.
+#[derive(Debug, Copy, Clone)]
+struct LiveCombo {
+    cards: [Card; 2],
+    set: CardSet,
+    // The combo's position within a `Range`, used to cache its evaluation
+    index: usize,
+    weight: f64,
+}
+
+/// Validate the inputs of a range-vs-range calculation, returning the set of
+/// board and dead cards along with the combos of each range that avoid them.
+fn live_combos(
+    ranges: &[Range],
+    board: &[Card],
+    dead: &[Card],
+) -> Result<(CardSet, Vec<Vec<LiveCombo>>), EvalError> {
+    if board.len() > 5 {
+        return Err(EvalError::InvalidBoardSize(board.len()));
+    }
+    if ranges.is_empty() {
+        return Err(EvalError::InvalidPlayerCount(0));
+    }
+    let known_cards = board.iter().chain(dead).copied().collect::<Vec<_>>();
+    if !utils::all_unique(&known_cards) {
+        return Err(EvalError::cards_not_unique(&known_cards));
+    }
+    let known = CardSet::from(&*known_cards);
+
+    let live = ranges
+        .iter()
+        .enumerate()
+        .map(|(position, range)| {
+            let combos = range
+                .combos()
+                .filter_map(|(cards, weight)| {
+                    let set = CardSet::from(cards);
+                    known.is_disjoint(set).then(|| LiveCombo {
+                        cards,
+                        set,
+                        index: range::combo_index(cards)
+                            .expect("range combos are never pairs of the same card"),
+                        weight,
+                    })
+                })
+                .collect::<Vec<_>>();
+            if combos.is_empty() {
+                Err(EvalError::EmptyRange(position))
+            } else {
+                Ok(combos)
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let needed = 5 - board.len() + 2 * ranges.len();
+    let available = CardSet::FULL.len() - known.len();
+    if available < needed {
+        return Err(EvalError::NotEnoughCards { needed, available });
+    }
+    if !has_matchup(&live, known) {
+        return Err(EvalError::NoValidMatchup);
+    }
+    Ok((known, live))
+}
+
+/// Check whether one combo can be dealt from each range without any of them
+/// sharing a card with each other or with `used`.
+fn has_matchup(live: &[Vec<LiveCombo>], used: CardSet) -> bool {
+    match live.split_first() {
+        None => true,
+        Some((combos, rest)) => combos
+            .iter()
+            .any(|combo| used.is_disjoint(combo.set) && has_matchup(rest, used | combo.set)),
+    }
+}
+
+/// Call `f` with every way to deal one combo from each range without any of
+/// them sharing a card with each other or with `used`, along with the product
+/// of the combos' weights.
+fn for_each_matchup<F>(
+    live: &[Vec<LiveCombo>],
+    used: CardSet,
+    chosen: &mut Vec<LiveCombo>,
+    weight: f64,
+    f: &mut F,
+) where
+    F: FnMut(&[LiveCombo], f64),
+{
+    match live.split_first() {
+        None => f(chosen, weight),
+        Some((combos, rest)) => {
+            for &combo in combos {
+                if used.is_disjoint(combo.set) {
+                    chosen.push(combo);
+                    for_each_matchup(rest, used | combo.set, chosen, weight * combo.weight, f);
+                    chosen.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Evaluate a single player's hole cards against a complete board.
+fn evaluate_combo(evaluator: &Evaluator, board: &[Card; 5], [first, second]: [Card; 2]) -> Eval {
+    let hand = [
+        board[0], board[1], board[2], board[3], board[4], first, second,
+    ];
+    evaluator
+        .evaluate_unique(&hand)
+        .expect("seven unique cards can always be evaluated")
+}
+
+/// Validate the cards involved in an equity calculation and find the cards
+/// that can still be dealt to the board.
+pub(crate) fn remaining_deck(
+    hole_cards: &[[Card; 2]],
+    board: &[Card],
+    dead: &[Card],
+) -> Result<CardSet, EvalError> {
+    if board.len() > 5 {
+        return Err(EvalError::InvalidBoardSize(board.len()));
+    }
+    if hole_cards.is_empty() {
+        return Err(EvalError::InvalidPlayerCount(0));
+    }
+
+    let all_cards = hole_cards
+        .iter()
+        .flatten()
+        .chain(board)
+        .chain(dead)
+        .copied()
+        .collect::<Vec<_>>();
+    if !utils::all_unique(&all_cards) {
+        return Err(EvalError::cards_not_unique(&all_cards));
+    }
+
+    let deck = CardSet::FULL - CardSet::from(&*all_cards);
+    if deck.len() < 5 - board.len() {
+        return Err(EvalError::NotEnoughCards {
+            needed: 5 - board.len(),
+            available: deck.len(),
+        });
+    }
+    Ok(deck)
+}
+
+/// Call `f` with every combination of `count` cards from `deck`.
+pub(crate) fn for_each_runout(deck: &[Card], count: usize, mut f: impl FnMut(&[Card])) {
+    debug_assert!(deck.len() >= count);
+    match count {
+        0 => f(&[]),
+        1 => utils::const_combos::<_, 1>(deck).for_each(|runout| f(&runout)),
+        2 => utils::const_combos::<_, 2>(deck).for_each(|runout| f(&runout)),
+        3 => utils::const_combos::<_, 3>(deck).for_each(|runout| f(&runout)),
+        4 => utils::const_combos::<_, 4>(deck).for_each(|runout| f(&runout)),
+        5 => utils::const_combos::<_, 5>(deck).for_each(|runout| f(&runout)),
+        _ => unreachable!("a board never needs more than 5 cards"),
+    }
+}
+
+/// Fold every combination of `count` cards from `deck` into its own state from
+/// `init`, with one part for each card that can be the lowest of a combination,
+/// and return the parts in order.
+///
+/// The parts don't depend on how the work is scheduled, so combining them in
+/// order gives the same result whether or not they were folded in `parallel`,
+/// and no matter how many threads there are.
+fn fold_runouts<T, I, F>(deck: &[Card], count: usize, parallel: bool, init: I, f: F) -> Vec<T>
+where
+    T: Send,
+    I: Fn() -> T + Sync,
+    F: Fn(&mut T, &[Card]) + Sync,
+{
+    debug_assert!(deck.len() >= count);
+    if count == 0 {
+        let mut state = init();
+        f(&mut state, &[]);
+        return vec![state];
+    }
+
+    let part = |first: usize| {
+        let mut state = init();
+        let mut runout = [deck[first]; 5];
+        for_each_runout(&deck[first + 1..], count - 1, |rest| {
+            runout[1..count].copy_from_slice(rest);
+            f(&mut state, &runout[..count]);
+        });
+        state
+    };
+    let parts = 0..deck.len() - count + 1;
+    #[cfg(feature = "rayon")]
+    if parallel {
+        return parts.into_par_iter().map(part).collect();
+    }
+    #[cfg(not(feature = "rayon"))]
+    debug_assert!(!parallel, "parallel folds need the `rayon` feature");
+    parts.map(part).collect()
+}
+
+/// Combine a partial board with the cards that complete it.
+pub(crate) fn complete_board(board: &[Card], runout: &[Card]) -> [Card; 5] {
+    debug_assert_eq!(board.len() + runout.len(), 5);
+    let mut cards = board.iter().chain(runout);
+    [(); 5].map(|_| *cards.next().unwrap())
+}
+
+/// Evaluate every player's hand against a complete board, writing the results
+/// into `evals`.
+pub(crate) fn showdown(
+    evaluator: &Evaluator,
+    hole_cards: &[[Card; 2]],
+    board: &[Card; 5],
+    evals: &mut Vec<Eval>,
+) {
+    evals.clear();
+    evals.extend(
+        hole_cards
+            .iter()
+            .map(|&cards| evaluate_combo(evaluator, board, cards)),
+    );
+}
+
+/// Find the best hand of a showdown, and how many players have it.
+pub(crate) fn best_hand(evals: &[Eval]) -> (Eval, usize) {
+    let best = evals.iter().copied().max().unwrap_or(Eval::WORST);
+    let winners = evals.iter().filter(|&&eval| eval == best).count();
+    (best, winners)
+}
+
+/// Award the pot of a single showdown to the players with the best hand.
+pub(crate) fn record_showdown(evals: &[Eval], results: &mut [Equity]) {
+    let (best, winners) = best_hand(evals);
+    for (&eval, result) in evals.iter().zip(results) {
+        result.record(if eval == best { winners } else { 0 });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cards, evaluate::tests::EVALUATOR};
+
+    fn parse(cards: &str) -> Vec<Card> {
+        Card::parse_to_iter(cards.split_whitespace())
+            .try_collect()
+            .unwrap()
+    }
+
+    #[test]
+    fn river_is_a_single_showdown() {
+        let players = [
+            cards!(Ace of Spades, King of Spades),
+            cards!(Two of Clubs, Seven of Diamonds),
+        ];
+        let results = exact(&EVALUATOR, &players, &parse("Qs Js Ts 3h 4d"), &[]).unwrap();
+        assert_eq!(results[0].wins(), 1);
+        assert_eq!(results[1].losses(), 1);
+        assert_eq!(results[0].equity(), 1.0);
+        assert_eq!(results[1].equity(), 0.0);
+    }
+
+    #[test]
+    fn split_pots_are_shared() {
+        // Both players play the board
+        let players = [
+            cards!(Two of Clubs, Three of Clubs),
+            cards!(Two of Diamonds, Three of Diamonds),
+            cards!(Four of Hearts, Five of Spades),
+        ];
+        let results = exact(&EVALUATOR, &players, &parse("As Ks Qs Js Ts"), &[]).unwrap();
+        for result in results {
+            assert_eq!(result.ties(), 1);
+            assert!((result.equity() - 1.0 / 3.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn turn_equities_add_up() {
+        let players = [
+            cards!(Ace of Hearts, King of Hearts),
+            cards!(Queen of Spades, Queen of Clubs),
+            cards!(Seven of Clubs, Eight of Clubs),
+        ];
+        let dead = parse("2h 9c");
+        let results = exact(&EVALUATOR, &players, &parse("Qh 7h 2d 3c"), &dead).unwrap();
+        assert!(results
+            .iter()
+            .all(|result| result.total() == 52 - 6 - 4 - 2));
+        let total: f64 = results.iter().map(|result| result.equity()).sum();
+        assert!((total - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn aces_against_kings_preflop() {
+        // With no suits in common, aces win 81.07% of the time and tie 0.38%
+        let players = [
+            cards!(Ace of Spades, Ace of Hearts),
+            cards!(King of Clubs, King of Diamonds),
+        ];
+        let results = exact(&EVALUATOR, &players, &[], &[]).unwrap();
+        assert_eq!(results[0].total(), 1_712_304);
+        assert_eq!(results[0].wins(), 1_388_072);
+        assert_eq!(results[0].ties(), 6_538);
+        assert_eq!(results[1].wins(), results[0].losses());
+        assert!((results[0].equity() - 0.8126).abs() < 0.0001);
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        let players = [cards!(Ace of Hearts, King of Hearts)];
+        assert_eq!(
+            exact(&EVALUATOR, &players, &parse("2c 3c 4c 5c 6c 7c"), &[]),
+            Err(EvalError::InvalidBoardSize(6))
+        );
+        assert_eq!(
+            exact(&EVALUATOR, &[], &[], &[]),
+            Err(EvalError::InvalidPlayerCount(0))
+        );
+        assert!(matches!(
+            exact(&EVALUATOR, &players, &parse("Ah 2c 3c"), &[]),
+            Err(EvalError::CardsNotUnique(_))
+        ));
+        let dead = deck_except(&parse("Ah Kh 2c 3c 4c 5c"));
+        let result = exact(&EVALUATOR, &players, &parse("2c 3c"), &dead);
+        assert_eq!(
+            result,
+            Err(EvalError::NotEnoughCards {
+                needed: 3,
+                available: 2
+            })
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Cannot deal 3 more cards with only 2 cards left in the deck"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn monte_carlo_is_reproducible() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha8Rng;
+
+        let players = [
+            cards!(Ace of Spades, Ace of Hearts),
+            cards!(King of Clubs, King of Diamonds),
+        ];
+        let budget = Budget::Iterations(20_000);
+        let run = |seed| {
+            let mut rng = ChaCha8Rng::seed_from_u64(seed);
+            monte_carlo(&EVALUATOR, &players, &[], &[], budget, &mut rng).unwrap()
+        };
+
+        let results = run(3);
+        assert_eq!(results, run(3));
+        assert_ne!(results, run(4));
+        assert!(results
+            .iter()
+            .all(|result| result.outcome().total() == 20_000));
+
+        // Within four standard errors of the exact result
+        let estimate = results[0];
+        assert!((estimate.equity() - 0.8126).abs() < 4.0 * estimate.standard_error());
+        assert!(estimate.standard_error() < 0.005);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn monte_carlo_stops_at_target() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha8Rng;
+
+        let players = [
+            cards!(Ace of Hearts, King of Hearts),
+            cards!(Queen of Spades, Queen of Clubs),
+        ];
+        let budget = Budget::StandardError {
+            target: 0.01,
+            max_iterations: 1_000_000,
+        };
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let results = monte_carlo(&EVALUATOR, &players, &[], &[], budget, &mut rng).unwrap();
+        let total = results[0].outcome().total();
+        assert!(total < 1_000_000);
+        assert_eq!(total % 1000, 0);
+        assert!(results.iter().all(|result| result.standard_error() <= 0.01));
+
+        // A river board has only one possible outcome
+        let board = parse("Qh 7h 2d 3c 9h");
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let results = monte_carlo(&EVALUATOR, &players, &board, &[], budget, &mut rng).unwrap();
+        assert_eq!(results[0].outcome().total(), 1000);
+        assert_eq!(results[0].standard_error(), 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn monte_carlo_rejects_empty_budget() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha8Rng;
+
+        let players = [
+            cards!(Ace of Hearts, King of Hearts),
+            cards!(Queen of Spades, Queen of Clubs),
+        ];
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for budget in [
+            Budget::Iterations(0),
+            Budget::StandardError {
+                target: 0.01,
+                max_iterations: 0,
+            },
+        ] {
+            assert_eq!(
+                monte_carlo(&EVALUATOR, &players, &[], &[], budget, &mut rng),
+                Err(EvalError::EmptyBudget)
+            );
+            assert_eq!(
+                monte_carlo_ranges(
+                    &EVALUATOR,
+                    &ranges(&["AK", "QQ"]),
+                    &[],
+                    &[],
+                    budget,
+                    &mut rng
+                ),
+                Err(EvalError::EmptyBudget)
+            );
+        }
+    }
+
+    fn ranges(ranges: &[&str]) -> Vec<Range> {
+        ranges.iter().map(|range| range.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn single_combo_ranges_match_hole_cards() {
+        let board = parse("Qh 7h 2d 3c");
+        let players = [
+            cards!(Ace of Hearts, King of Hearts),
+            cards!(Queen of Spades, Queen of Clubs),
+        ];
+        let expected = exact(&EVALUATOR, &players, &board, &[]).unwrap();
+        let results = exact_ranges(&EVALUATOR, &ranges(&["AhKh", "QsQc"]), &board, &[]).unwrap();
+        for (result, expected) in results.iter().zip(&expected) {
+            assert!((result.equity() - expected.equity()).abs() < 1e-12);
+            assert!((result.win_fraction() - expected.win_fraction()).abs() < 1e-12);
+            assert!((result.loss_fraction() - expected.loss_fraction()).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn ranges_are_weighted_averages_of_matchups() {
+        let board = parse("Qh 7h 2d 3c");
+        let dead = parse("Kd");
+        let ranges = ranges(&["AK:0.5, QQ, AhKh", "JJ+:0.25, 76s, 54"]);
+        let results = exact_ranges(&EVALUATOR, &ranges, &board, &dead).unwrap();
+
+        let known = CardSet::from(&*board) | CardSet::from(&*dead);
+        let (mut total_weight, mut share) = (0.0, 0.0);
+        for (first, first_weight) in ranges[0].combos() {
+            for (second, second_weight) in ranges[1].combos() {
+                let cards = CardSet::from(first) | CardSet::from(second);
+                if cards.len() < 4 || !cards.is_disjoint(known) {
+                    continue;
+                }
+                let weight = first_weight * second_weight;
+                let equity = exact(&EVALUATOR, &[first, second], &board, &dead).unwrap();
+                total_weight += weight;
+                share += weight * equity[0].equity();
+            }
+        }
+        assert!((results[0].equity() - share / total_weight).abs() < 1e-12);
+        assert!((results[0].equity() + results[1].equity() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn range_card_removal() {
+        // Kings can only be dealt when the other range doesn't hold a king
+        let board = parse("2c 7d 9h Jc 3s");
+        let results = exact_ranges(&EVALUATOR, &ranges(&["KK", "KsKh, 22"]), &board, &[]).unwrap();
+        // Only KdKc can be dealt against KsKh, and ties it. Against the three
+        // combos of 22 left by the board, all six combos of KK lose to a set
+        let weight = 1.0 + 3.0 * 6.0;
+        assert!((results[0].tie_fraction() - 1.0 / weight).abs() < 1e-12);
+        assert!((results[0].loss_fraction() - 18.0 / weight).abs() < 1e-12);
+    }
+
+    #[test]
+    fn invalid_range_inputs() {
+        let board = parse("Ks Kh 7c");
+        assert_eq!(
+            exact_ranges(&EVALUATOR, &ranges(&["AA", "KK"]), &board, &parse("Kd Kc")),
+            Err(EvalError::EmptyRange(1))
+        );
+        assert_eq!(
+            exact_ranges(&EVALUATOR, &ranges(&["AsAh", "AsAc"]), &board, &[]),
+            Err(EvalError::NoValidMatchup)
+        );
+        let dead = deck_except(&parse("Ks Kh 7c As Ah Ac Ad"));
+        assert_eq!(
+            exact_ranges(&EVALUATOR, &ranges(&["AsAh", "AcAd"]), &board, &dead),
+            Err(EvalError::NotEnoughCards {
+                needed: 6,
+                available: 4
+            })
+        );
+        assert_eq!(
+            exact_ranges(&EVALUATOR, &[], &board, &[]),
+            Err(EvalError::InvalidPlayerCount(0))
+        );
+        assert!(matches!(
+            exact_ranges(&EVALUATOR, &ranges(&["AA"]), &board, &parse("7c")),
+            Err(EvalError::CardsNotUnique(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn monte_carlo_ranges_approaches_exact() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha8Rng;
+
+        let board = parse("Qh 7h 2d");
+        let ranges = ranges(&["AK:0.5, QQ, AhKh, 8h9h", "JJ+:0.25, 76s, 54, A7"]);
+        let expected = exact_ranges(&EVALUATOR, &ranges, &board, &[]).unwrap();
+
+        let run = |seed| {
+            let mut rng = ChaCha8Rng::seed_from_u64(seed);
+            let budget = Budget::Iterations(20_000);
+            monte_carlo_ranges(&EVALUATOR, &ranges, &board, &[], budget, &mut rng).unwrap()
+        };
+        let results = run(5);
+        assert_eq!(results, run(5));
+        for (estimate, expected) in results.iter().zip(&expected) {
+            assert!(
+                (estimate.equity() - expected.equity()).abs() < 4.0 * estimate.standard_error()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn parallel_results_match_sequential() {
+        let players = [
+            cards!(Ace of Hearts, King of Hearts),
+            cards!(Queen of Spades, Queen of Clubs),
+            cards!(Seven of Clubs, Eight of Clubs),
+        ];
+        let ranges = ranges(&["AK:0.5, QQ, AhKh, 8h9h", "JJ+:0.25, 76s, 54, A7"]);
+        let dead = parse("9c");
+        for board in ["Qh 7h 2d", "Qh 7h 2d 3c", "Qh 7h 2d 3c Js"] {
+            let board = parse(board);
+            let expected = exact(&EVALUATOR, &players, &board, &dead).unwrap();
+            let expected_ranges = (board.len() >= 3)
+                .then(|| exact_ranges(&EVALUATOR, &ranges, &board, &dead).unwrap());
+            for threads in [1, 4] {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .unwrap();
+                pool.install(|| {
+                    assert_eq!(
+                        par_exact(&EVALUATOR, &players, &board, &dead).unwrap(),
+                        expected
+                    );
+                    if let Some(expected) = &expected_ranges {
+                        assert_eq!(
+                            &par_exact_ranges(&EVALUATOR, &ranges, &board, &dead).unwrap(),
+                            expected
+                        );
+                    }
+                });
+            }
+        }
+        assert_eq!(
+            par_exact(&EVALUATOR, &players, &parse("Ah 2c 3c"), &[]),
+            exact(&EVALUATOR, &players, &parse("Ah 2c 3c"), &[])
+        );
+    }
+
+    fn deck_except(cards: &[Card]) -> Vec<Card> { (CardSet::FULL - CardSet::from(cards)).to_vec() }
+}
@@ -1,15 +1,21 @@
-//! Two different error types that may be encountered when trying to parse
-//! [`Card`] types from strings, or when trying to evaluate hands.
+//! The error types that may be encountered when trying to parse [`Card`] types
+//! or ranges from strings, or when trying to evaluate hands.
 //!
-//! The [`Debug`](std::fmt::Debug) representations aren't *particularly*
-//! helpful, so try to display errors as [`Display`](std::fmt::Display) when
+//! The [`Debug`](core::fmt::Debug) representations aren't *particularly*
+//! helpful, so try to display errors as [`Display`](core::fmt::Display) when
 //! possible.
+//!
+//! Without the `std` feature, the errors can't hold on to heap-allocated data,
+//! so an [`OriginalInput`] doesn't keep the input it was made from.
+//!
+//! Every error enum is `#[non_exhaustive]`, so matching on one needs a wildcard
+//! arm, and new kinds of errors can be added without breaking changes.
 
-use std::{error::Error, fmt};
-
-use itertools::Itertools;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
 
-use crate::card::Card;
+use crate::card::{Card, CardSet, RankSuit};
 
 /// An error than can be thrown when parsing [`Card`] types from strings.
 ///
@@ -24,7 +30,9 @@ use crate::card::Card;
 /// assert_eq!(
 ///     result,
 ///     Err(ParseCardError::InvalidLength {
-///         original_input: "10c".into()
+///         original_input: "10c".into(),
+///         position: None,
+///         length: 3,
 ///     })
 /// );
 /// ```
@@ -41,6 +49,7 @@ use crate::card::Card;
 ///     result,
 ///     Err(ParseCardError::InvalidRank {
 ///         original_input: "jc".into(),
+///         position: None,
 ///         incorrect_char: 'j'
 ///     })
 /// );
@@ -58,28 +67,43 @@ use crate::card::Card;
 ///     result,
 ///     Err(ParseCardError::InvalidSuit {
 ///         original_input: "2D".into(),
+///         position: None,
 ///         incorrect_char: 'D'
 ///     })
 /// );
 /// ```
+///
+/// Forms like "10c", "jc" and "2D" can be parsed with [`Card::parse_with`] and
+/// [`CardNotation::LENIENT`](crate::CardNotation::LENIENT). When a whole hand
+/// is parsed at once, as with [`Card::parse_hand`], the `original_input` of an
+/// error is the whole hand, and its `position` points at the card or character
+/// that couldn't be parsed. A single card has no `position`.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum ParseCardError {
     /// A string to be interpreted as a [`Card`] must be exactly two characters
     /// long. This variant is used if the input does not have meet this
     /// criterion.
     InvalidLength {
-        /// The input that incited this error, converted to a [`String`] if
-        /// needed. The original input is also used for calculating and
-        /// reporting the incorrect number of characters received.
-        original_input: String,
+        /// The input that incited this error.
+        original_input: OriginalInput,
+        /// Where the card with the wrong length starts within a hand, counted
+        /// in characters, or `None` if the input was parsed as a single card.
+        position: Option<usize>,
+        /// The number of characters in the card, not counting any brackets
+        /// around it. For a single card, this is the whole input.
+        length: usize,
     },
     /// A string to be interpreted as a [`Card`] must have its first character
     /// be one of "23456789TJQKA", otherwise, this error will be thrown and
     /// indicate the incorrect character.
     InvalidRank {
-        /// The input that incited this error, converted to a [`String`] if
-        /// needed.
-        original_input: String,
+        /// The input that incited this error.
+        original_input: OriginalInput,
+        /// Where the incorrect character is within a hand, counted in
+        /// characters, or `None` if the input was parsed as a single card.
+        position: Option<usize>,
         /// The actual character within the input that was unexpected and could
         /// not be interpreted as a card rank.
         incorrect_char: char,
@@ -88,47 +112,316 @@ pub enum ParseCardError {
     /// be one of "23456789TJQKA", otherwise, this error will be thrown and
     /// indicate the incorrect character.
     InvalidSuit {
-        /// The input that incited this error, converted to a [`String`] if
-        /// needed.
-        original_input: String,
+        /// The input that incited this error.
+        original_input: OriginalInput,
+        /// Where the incorrect character is within a hand, counted in
+        /// characters, or `None` if the input was parsed as a single card.
+        position: Option<usize>,
         /// The actual character within the input that was unexpected and could
         /// not be interpreted as a card suit.
         incorrect_char: char,
     },
+    /// A hand parsed with [`Duplicates::Reject`] held the same card more than
+    /// once. This variant is used for the second time the card appears.
+    ///
+    /// [`Duplicates::Reject`]: crate::Duplicates::Reject
+    DuplicateCard {
+        /// The input that incited this error.
+        original_input: OriginalInput,
+        /// Where the card appears again within the input, counted in
+        /// characters.
+        position: usize,
+        /// The card that appeared more than once.
+        card: Card,
+    },
+}
+
+impl ParseCardError {
+    /// The input that incited this error, whichever kind of error it is.
+    pub fn original_input(&self) -> &OriginalInput {
+        match self {
+            Self::InvalidLength { original_input, .. }
+            | Self::InvalidRank { original_input, .. }
+            | Self::InvalidSuit { original_input, .. }
+            | Self::DuplicateCard { original_input, .. } => original_input,
+        }
+    }
+
+    pub(crate) fn invalid_length(
+        original_input: &str,
+        position: Option<usize>,
+        length: usize,
+    ) -> Self {
+        Self::InvalidLength {
+            original_input: original_input.into(),
+            position,
+            length,
+        }
+    }
+
+    pub(crate) fn invalid_rank(
+        original_input: &str,
+        position: Option<usize>,
+        incorrect_char: char,
+    ) -> Self {
+        Self::InvalidRank {
+            original_input: original_input.into(),
+            position,
+            incorrect_char,
+        }
+    }
+
+    pub(crate) fn invalid_suit(
+        original_input: &str,
+        position: Option<usize>,
+        incorrect_char: char,
+    ) -> Self {
+        Self::InvalidSuit {
+            original_input: original_input.into(),
+            position,
+            incorrect_char,
+        }
+    }
+
+    pub(crate) fn duplicate_card(original_input: &str, position: usize, card: Card) -> Self {
+        Self::DuplicateCard {
+            original_input: original_input.into(),
+            position,
+            card,
+        }
+    }
+}
+
+/// The input that a [`ParseCardError`] was encountered in.
+///
+/// With the `std` feature, this holds on to a copy of the input, which is
+/// available from [`as_str`](OriginalInput::as_str). Without it, nothing is
+/// kept, and errors are reported with positions alone. Either way, one can be
+/// made from any `&str` with [`From`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OriginalInput {
+    #[cfg(feature = "std")]
+    input: String,
+}
+
+impl OriginalInput {
+    /// The input that incited the error.
+    #[cfg(feature = "std")]
+    pub fn as_str(&self) -> &str { &self.input }
+}
+
+impl From<&str> for OriginalInput {
+    #[cfg_attr(not(feature = "std"), allow(unused_variables))]
+    fn from(input: &str) -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            input: input.to_string(),
+        }
+    }
 }
 
 impl fmt::Display for ParseCardError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "std")]
+        write!(
+            f,
+            "Error parsing input '{}' as a Card: ",
+            self.original_input().as_str()
+        )?;
+        #[cfg(not(feature = "std"))]
+        f.write_str("Error parsing input as a Card: ")?;
         match *self {
-            Self::InvalidLength { ref original_input } => write!(
+            Self::InvalidLength {
+                position: None,
+                length,
+                ..
+            } => write!(f, "Found input of length {}, expected 2", length),
+            Self::InvalidLength {
+                position: Some(position),
+                length,
+                ..
+            } => write!(
                 f,
-                "Error parsing input '{}' as a Card: Found input of length {}, expected 2",
-                original_input,
-                original_input.len()
+                "Found a card of length {} at position {}, expected 2",
+                length, position
             ),
             Self::InvalidRank {
-                ref original_input,
+                position,
                 incorrect_char,
+                ..
+            } => {
+                write!(f, "Invalid rank character '{}'", incorrect_char)?;
+                write_position(f, position)?;
+                f.write_str(", expected one of [23456789TJQKA]")
+            }
+            Self::InvalidSuit {
+                position,
+                incorrect_char,
+                ..
+            } => {
+                write!(f, "Invalid suit character '{}'", incorrect_char)?;
+                write_position(f, position)?;
+                f.write_str(", expected one of [chsd]")
+            }
+            Self::DuplicateCard { position, card, .. } => write!(
+                f,
+                "Duplicate card '{}' at position {}",
+                RankSuit(card),
+                position
+            ),
+        }
+    }
+}
+
+/// Point out where in a hand an error is, if it is in a hand at all.
+fn write_position(f: &mut fmt::Formatter<'_>, position: Option<usize>) -> fmt::Result {
+    match position {
+        Some(position) => write!(f, " at position {}", position),
+        None => Ok(()),
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseCardError {}
+
+/// An error than can be thrown when parsing a [`Range`] from a string.
+///
+/// # Examples
+///
+/// Each comma-separated part of a range must describe a group of hands, such
+/// as "QQ+", "AKs", "A5s-A2s", or "AhKh". Anything else yields
+/// [`ParseRangeError::InvalidHand`].
+///
+/// ```
+/// use poker::{ParseRangeError, Range};
+/// let result = "QQ+, AKx".parse::<Range>();
+/// assert_eq!(
+///     result,
+///     Err(ParseRangeError::InvalidHand {
+///         original_input: "QQ+, AKx".into(),
+///         hand: "AKx".into(),
+///     })
+/// );
+/// ```
+///
+/// A weight, given after a colon, must be a number greater than 0 and at most
+/// 1, otherwise [`ParseRangeError::InvalidWeight`] is thrown.
+///
+/// ```
+/// use poker::{ParseRangeError, Range};
+/// let result = "76s:1.5".parse::<Range>();
+/// assert_eq!(
+///     result,
+///     Err(ParseRangeError::InvalidWeight {
+///         original_input: "76s:1.5".into(),
+///         weight: "1.5".into(),
+///     })
+/// );
+/// ```
+///
+/// [`Range`]: crate::Range
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum ParseRangeError {
+    /// A part of the range could not be interpreted as a group of hands. This
+    /// includes spans like "A5s-K2s", whose ends don't share a high card.
+    InvalidHand {
+        /// The input that incited this error, converted to a [`String`] if
+        /// needed.
+        original_input: String,
+        /// The part of the input that could not be interpreted as hands.
+        hand: String,
+    },
+    /// A weight could not be interpreted as a number greater than 0 and at
+    /// most 1.
+    InvalidWeight {
+        /// The input that incited this error, converted to a [`String`] if
+        /// needed.
+        original_input: String,
+        /// The part of the input that could not be interpreted as a weight.
+        weight: String,
+    },
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for ParseRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::InvalidHand {
+                ref original_input,
+                ref hand,
             } => write!(
                 f,
-                "Error parsing input '{}' as a Card: Invalid rank character '{}', expected one of \
-                 [23456789TJQKA]",
-                original_input, incorrect_char
+                "Error parsing input '{}' as a Range: Invalid hand '{}', expected a pair, a \
+                 suited or offsuit hand, a span, or two specific cards",
+                original_input, hand
             ),
-            Self::InvalidSuit {
+            Self::InvalidWeight {
                 ref original_input,
-                incorrect_char,
+                ref weight,
             } => write!(
                 f,
-                "Error parsing input '{}' as a Card: Invalid suit character '{}', expected one of \
-                 [chsd]",
-                original_input, incorrect_char
+                "Error parsing input '{}' as a Range: Invalid weight '{}', expected a number \
+                 greater than 0 and at most 1",
+                original_input, weight
             ),
         }
     }
 }
 
-impl Error for ParseCardError {}
+#[cfg(feature = "std")]
+impl Error for ParseRangeError {}
+
+/// An error that can be thrown when parsing a [`StartingHand`] from a string.
+///
+/// A starting hand must be written as two ranks followed by "s" for suited or
+/// "o" for offsuit, such as "AKs" or "72o", or as a pair, such as "TT".
+/// Anything else yields [`ParseStartingHandError::InvalidHand`].
+///
+/// ```
+/// use poker::{ParseStartingHandError, StartingHand};
+/// let result = "AK".parse::<StartingHand>();
+/// assert_eq!(
+///     result,
+///     Err(ParseStartingHandError::InvalidHand {
+///         original_input: "AK".into(),
+///     })
+/// );
+/// ```
+///
+/// [`StartingHand`]: crate::StartingHand
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum ParseStartingHandError {
+    /// The input could not be interpreted as a pair, a suited hand, or an
+    /// offsuit hand.
+    InvalidHand {
+        /// The input that incited this error, converted to a [`String`] if
+        /// needed.
+        original_input: String,
+    },
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for ParseStartingHandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::InvalidHand { ref original_input } => write!(
+                f,
+                "Error parsing input '{}' as a StartingHand: Expected a pair like 'TT', or two \
+                 ranks followed by 's' or 'o' like 'AKs'",
+                original_input
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseStartingHandError {}
 
 /// An error that can be thrown when evaluating poker hands.
 ///
@@ -151,7 +444,7 @@ impl Error for ParseCardError {}
 /// let result = eval.evaluate(&hand);
 /// assert_eq!(
 ///     result,
-///     Err(EvalError::CardsNotUnique(hand.clone()))
+///     Err(EvalError::CardsNotUnique(cards!(King, Clubs).into()))
 /// );
 /// ```
 ///
@@ -175,38 +468,64 @@ impl Error for ParseCardError {}
 /// );
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum EvalError {
     /// This variant is used when the cards to be evaluated are not all unique.
-    /// This captures the entire original hand, and the duplicates are
-    /// calculated when reporting in [`Display`](std::fmt::Display) format.
-    CardsNotUnique(Vec<Card>),
-    /// This variant is used when the cards to be evaluated total to 4 or less.
+    /// This captures every card that appears more than once.
+    CardsNotUnique(CardSet),
+    /// This variant is used when the cards to be evaluated total to 4 or less,
+    /// or when an evaluator needs an exact number of cards and is given a
+    /// different number, like the [`SevenCardEvaluator`].
+    ///
+    /// [`SevenCardEvaluator`]: crate::SevenCardEvaluator
     InvalidHandSize(usize),
+    /// This variant is used when a board has more or fewer cards than the game
+    /// allows, such as a Texas Holdem board with more than 5 cards, or an Omaha
+    /// board with fewer than 3.
+    InvalidBoardSize(usize),
+    /// This variant is used when a player is given the wrong number of hole
+    /// cards for the game, such as an Omaha hand with fewer than 4 or more
+    /// than 6 hole cards.
+    InvalidHoleCardCount(usize),
+    /// This variant is used when there are no players to evaluate.
+    InvalidPlayerCount(usize),
+    /// This variant is used when the deck runs out before every player has
+    /// been dealt in and the board has been completed, such as when too many
+    /// cards are dead.
+    NotEnoughCards {
+        /// The number of cards that still need to be dealt.
+        needed: usize,
+        /// The number of cards left in the deck.
+        available: usize,
+    },
+    /// This variant is used when every range in a range-vs-range calculation
+    /// has combos left, but there is no way to deal one combo from each range
+    /// without two of them sharing a card.
+    NoValidMatchup,
+    /// This variant is used when a Monte Carlo estimate is given a budget of
+    /// zero runouts, which leaves nothing to estimate from.
+    EmptyBudget,
+    /// This variant is used when the range at the given position has no
+    /// combos left once the board and dead cards are removed from it.
+    EmptyRange(usize),
+    /// This variant is used when a card can't be dealt from the deck the game
+    /// is played with, such as a five in short-deck Hold'em.
+    CardNotInDeck(Card),
 }
 
 impl fmt::Display for EvalError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             Self::CardsNotUnique(ref cards) => {
-                let dups: String = cards
-                    .iter()
-                    .counts()
-                    .into_iter()
-                    .filter_map(|(card, count)| {
-                        if count > 1 {
-                            Some(card.rank_suit_string())
-                        } else {
-                            None
-                        }
-                    })
-                    .collect::<Vec<_>>()
-                    .join(" ");
                 write!(
                     f,
                     "Cannot evaluate a poker hand with a set of cards that are not unique. Cards \
-                     duplicated at least once: {}",
-                    dups
-                )
+                     duplicated at least once:"
+                )?;
+                cards
+                    .iter()
+                    .try_for_each(|card| write!(f, " {}", RankSuit(card)))
             }
             Self::InvalidHandSize(size) => write!(
                 f,
@@ -214,12 +533,67 @@ impl fmt::Display for EvalError {
                  received: {}",
                 size
             ),
+            Self::InvalidBoardSize(size) => write!(
+                f,
+                "Cannot evaluate poker hands with a board of {} cards",
+                size
+            ),
+            Self::CardNotInDeck(card) => write!(
+                f,
+                "Cannot evaluate a poker hand containing {}, which isn't in the deck",
+                RankSuit(card)
+            ),
+            Self::InvalidHoleCardCount(count) => {
+                write!(f, "Cannot evaluate a poker hand with {} hole cards", count)
+            }
+            Self::InvalidPlayerCount(count) => write!(
+                f,
+                "Cannot evaluate poker hands for {} players with the cards available",
+                count
+            ),
+            Self::NotEnoughCards { needed, available } => write!(
+                f,
+                "Cannot deal {} more cards with only {} cards left in the deck",
+                needed, available
+            ),
+            Self::NoValidMatchup => write!(
+                f,
+                "Cannot evaluate ranges that have no combos that can be dealt together"
+            ),
+            Self::EmptyBudget => write!(
+                f,
+                "Cannot estimate equity without sampling at least one runout"
+            ),
+            Self::EmptyRange(position) => write!(
+                f,
+                "Cannot evaluate range {} as it has no combos that can be dealt",
+                position
+            ),
         }
     }
 }
 
+#[cfg(any(feature = "std", feature = "static_lookup"))]
+impl EvalError {
+    /// The error for a hand whose `cards` are not all unique.
+    pub(crate) fn cards_not_unique(cards: &[Card]) -> Self {
+        Self::CardsNotUnique(duplicates(cards))
+    }
+}
+
+#[cfg(feature = "std")]
 impl Error for EvalError {}
 
+/// The cards that appear more than once in `cards`.
+fn duplicates(cards: &[Card]) -> CardSet {
+    let mut seen = CardSet::new();
+    cards
+        .iter()
+        .filter(|&&card| !seen.insert(card))
+        .copied()
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{cards, Card};
@@ -258,6 +632,17 @@ mod tests {
                 "TC", 'C'
             )
         );
+
+        // A single card is measured as a whole, whatever it holds
+        for (input, length) in [(" As", 3), ("A s", 3), ("AsX", 3), ("10c", 3)] {
+            assert_eq!(
+                input.parse::<Card>().unwrap_err().to_string(),
+                format!(
+                    "Error parsing input '{}' as a Card: Found input of length {}, expected 2",
+                    input, length
+                )
+            );
+        }
     }
 
     #[test]
@@ -274,6 +659,9 @@ mod tests {
                 "5c"
             )
         );
+        let cards = cards!("Tc 5c Tc 5c Qc").try_collect::<Vec<Card>>().unwrap();
+        let e = EVALUATOR.evaluate(cards).unwrap_err();
+        assert!(e.to_string().ends_with("duplicated at least once: 5c Tc"));
 
         // Invalid hand size
         let cards = &cards!(Ace of Spades);
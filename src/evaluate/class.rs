@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 use crate::Rank;
 
@@ -7,6 +7,10 @@ use crate::Rank;
 /// poker hand. Royal flush is not included, but can be matched against
 /// `EvalClass:StraightFlush { high_card: Rank::Ace }` if desired.
 ///
+/// An `EvalClass` only carries the ranks that name the hand. The kickers that
+/// break ties between hands of the same class can be obtained with
+/// [`Eval::kickers`], and all five ranks with [`Eval::ranks`].
+///
 /// # Example
 ///
 /// ```
@@ -25,6 +29,8 @@ use crate::Rank;
 /// ```
 ///
 /// [`Eval::class`]: crate::Eval::class
+/// [`Eval::kickers`]: crate::Eval::kickers
+/// [`Eval::ranks`]: crate::Eval::ranks
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum EvalClass {
     /// A high card, or no hand.
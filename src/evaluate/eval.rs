@@ -1,8 +1,8 @@
-use std::fmt;
+use core::fmt;
 
 use crate::{
     evaluate::{hand_rank::PokerHandRank, meta::Meta},
-    EvalClass,
+    EvalClass, Rank,
 };
 
 /// The result of a successful poker hand evaluation. When printed in
@@ -54,6 +54,28 @@ use crate::{
 /// # Ok(())
 /// # }
 /// ```
+///
+/// The ranks that break ties between hands of the same class are available
+/// through [`kickers`](Eval::kickers), and [`ranks`](Eval::ranks) gives the
+/// full breakdown of all five ranks. When printed with the alternate
+/// [`Display`] flag (`{:#}`), the kickers are spelled out as well.
+///
+/// ```
+/// use poker::{cards, Evaluator, Rank};
+/// let hand = cards!("7c Kd 3s Qs 7h")
+///     .try_collect::<Vec<_>>()
+///     .expect("couldn't parse cards");
+/// let eval = Evaluator::new();
+/// let result = eval.evaluate(hand).expect("couldn't evaluate hand");
+/// assert_eq!(
+///     result.kickers().collect::<Vec<_>>(),
+///     [Rank::King, Rank::Queen, Rank::Three]
+/// );
+/// assert_eq!(
+///     format!("{:#}", result),
+///     "Pair of sevens, king-queen-three kickers"
+/// );
+/// ```
 /// [`Display`]: std::fmt::Display
 #[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
 pub struct Eval(pub(crate) Meta);
@@ -66,10 +88,129 @@ impl Eval {
 
     pub(crate) const fn hand_rank(self) -> PokerHandRank { self.0.hand_rank() }
 
+    /// A stable, numeric representation of this hand's strength, from 1 (the
+    /// worst possible hand, a seven-high) to 7462 (a royal flush). Two
+    /// evaluations compare the same way their strengths do, and every one of
+    /// the 7462 distinct poker hands has its own strength.
+    ///
+    /// This number is suitable for storing or sending evaluations elsewhere;
+    /// use [`from_strength`](Eval::from_strength) to get the full [`Eval`]
+    /// back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::Eval;
+    ///
+    /// assert_eq!(Eval::WORST.strength(), 1);
+    /// assert_eq!(Eval::BEST.strength(), 7462);
+    /// assert!(Eval::BEST.strength() > Eval::WORST.strength());
+    /// ```
+    pub const fn strength(self) -> u16 { (PokerHandRank::WORST.0 + 1 - self.hand_rank().0) as u16 }
+
+    /// Rebuild an [`Eval`] from its [`strength`](Eval::strength). Returns
+    /// `None` if `strength` is not in the range `1..=7462`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{cards, Eval, Evaluator};
+    ///
+    /// let eval = Evaluator::new();
+    /// let result = eval
+    ///     .evaluate(cards!(
+    ///         Jack of Diamonds,
+    ///         Four of Clubs,
+    ///         Jack of Clubs,
+    ///         Four of Spades,
+    ///         Jack of Hearts,
+    ///     ))
+    ///     .expect("couldn't evaluate hand");
+    /// let strength = result.strength();
+    /// assert_eq!(Eval::from_strength(strength), Some(result));
+    /// assert_eq!(
+    ///     Eval::from_strength(strength).map(|result| result.class()),
+    ///     Some(result.class())
+    /// );
+    /// assert_eq!(Eval::from_strength(0), None);
+    /// ```
+    pub fn from_strength(strength: u16) -> Option<Self> {
+        let worst = PokerHandRank::WORST.0 as u16;
+        if !(1..=worst).contains(&strength) {
+            return None;
+        }
+        let hand_rank = PokerHandRank((worst + 1 - strength) as i16);
+        Meta::from_hand_rank(hand_rank).map(Self)
+    }
+
+    /// An iterator over all 7462 distinct poker hand evaluations, from the
+    /// worst to the best, in ascending order of [`strength`](Eval::strength).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::Eval;
+    ///
+    /// let mut all = Eval::all();
+    /// assert_eq!(all.len(), 7462);
+    /// assert_eq!(all.next(), Some(Eval::WORST));
+    /// assert_eq!(all.next_back(), Some(Eval::BEST));
+    /// ```
+    pub fn all() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        (1..=PokerHandRank::WORST.0 as u16).map(|strength| {
+            Self::from_strength(strength).expect("strength should always be in range")
+        })
+    }
+
     /// The class of poker hand that was evaluated. Useful for pattern matching
     /// as opposed to checking with an `is_x()` method.
     pub const fn class(self) -> EvalClass { self.0.class() }
 
+    /// All five ranks of the evaluated hand, ordered by significance. Ranks of
+    /// the largest group of matching cards come first (quads, trips, then
+    /// pairs), followed by the remaining ranks from highest to lowest. In a
+    /// five-high straight, the ace comes last.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{cards, Evaluator, Rank};
+    ///
+    /// let eval = Evaluator::new();
+    /// let result = eval
+    ///     .evaluate(cards!(
+    ///         Nine of Clubs,
+    ///         Two of Hearts,
+    ///         Nine of Spades,
+    ///         Two of Diamonds,
+    ///         Ace of Clubs,
+    ///     ))
+    ///     .expect("couldn't evaluate hand");
+    /// assert_eq!(
+    ///     result.ranks(),
+    ///     [Rank::Nine, Rank::Nine, Rank::Two, Rank::Two, Rank::Ace]
+    /// );
+    /// ```
+    pub fn ranks(self) -> [Rank; 5] { self.0.ranks() }
+
+    /// The ranks that are not already described by the [`class`](Eval::class)
+    /// of this hand but still decide ties between hands of the same class,
+    /// from most to least significant. Straights, full houses and straight
+    /// flushes have no kickers. For flushes and high-card hands, every rank
+    /// below the highest one is a kicker.
+    pub fn kickers(self) -> impl DoubleEndedIterator<Item = Rank> + ExactSizeIterator {
+        let described = match self.class() {
+            EvalClass::HighCard { .. } | EvalClass::Flush { .. } => 1,
+            EvalClass::Pair { .. } => 2,
+            EvalClass::ThreeOfAKind { .. } => 3,
+            EvalClass::TwoPair { .. } | EvalClass::FourOfAKind { .. } => 4,
+            EvalClass::Straight { .. }
+            | EvalClass::FullHouse { .. }
+            | EvalClass::StraightFlush { .. } => 5,
+        };
+        self.ranks().into_iter().skip(described)
+    }
+
     /// Compare this hand evaluation to another, returning `true` if this hand
     /// definitively beats the other, and `false` otherwise. This is equivalent
     /// to the operation `self > other`, but is a `const fn`.
@@ -124,7 +265,37 @@ impl Eval {
 }
 
 impl fmt::Display for Eval {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.0.fmt(f) }
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !f.alternate() {
+            return self.0.fmt(f);
+        }
+
+        match self.class() {
+            EvalClass::HighCard { high_rank } => {
+                write!(f, "High card {}", high_rank.as_str_name())?
+            }
+            EvalClass::Pair { pair } => write!(f, "Pair of {}", pair.as_str_name_plural())?,
+            EvalClass::Flush { high_rank } => write!(f, "Flush, {}", high_rank.as_str_name())?,
+            _ => self.0.fmt(f)?,
+        }
+
+        let kickers = self.kickers();
+        let count = kickers.len();
+        let is_flush = self.is_flush();
+        for (i, kicker) in kickers.enumerate() {
+            let separator = match i {
+                0 if !is_flush => ", ",
+                _ => "-",
+            };
+            write!(f, "{}{}", separator, kicker.as_str_name())?;
+        }
+        match count {
+            _ if is_flush => Ok(()),
+            0 => Ok(()),
+            1 => write!(f, " kicker"),
+            _ => write!(f, " kickers"),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -147,6 +318,80 @@ mod tests {
         assert_eq!(result.to_string(), "High card, seven");
     }
 
+    #[test]
+    fn eval_kickers_display() {
+        let check = |cards: &str, expected: &str| {
+            let hand: Vec<_> = cards!(cards).try_collect().unwrap();
+            let result = EVALUATOR.evaluate(hand).unwrap();
+            assert_eq!(format!("{:#}", result), expected);
+        };
+
+        check(
+            "Ah 4c 9d Ks 6h",
+            "High card ace, king-nine-six-four kickers",
+        );
+        check("7c Kd 3s Qs 7h", "Pair of sevens, king-queen-three kickers");
+        check("Kd Qc Ks Jh Qd", "Two pair, kings and queens, jack kicker");
+        check("As Ac 2d 7h Ad", "Three of a kind, aces, seven-two kickers");
+        check("9c 5d 6h 8s 7s", "Straight, nine-high");
+        check("4h Ah 9h 6h Kh", "Flush, ace-king-nine-six-four");
+        check("Jd 4c Jc 4s Jh", "Full house, jacks over fours");
+        check("Ac Ad 2h As Ah", "Four of a kind, aces, two kicker");
+        check("Ts Js Qs Ks As", "Royal flush");
+    }
+
+    #[test]
+    fn eval_ranks_cover_lookup_table() {
+        use crate::{constants::PRIMES, evaluate::lookup_table::LookupTable};
+
+        let LookupTable {
+            flush_lookup,
+            unsuited_lookup,
+            ..
+        } = LookupTable::new();
+        for (product, meta) in flush_lookup.into_iter().chain(unsuited_lookup) {
+            let ranks = meta.ranks();
+            let ranks_product = ranks
+                .iter()
+                .fold(1, |acc, &rank| acc * PRIMES[rank as usize]);
+            assert_eq!(ranks_product, product, "wrong ranks for {:?}", meta);
+        }
+    }
+
+    #[test]
+    fn eval_strength_round_trip() {
+        use crate::evaluate::lookup_table::LookupTable;
+
+        let LookupTable {
+            flush_lookup,
+            unsuited_lookup,
+            ..
+        } = LookupTable::new();
+        for meta in flush_lookup
+            .into_values()
+            .chain(unsuited_lookup.into_values())
+        {
+            let result = Eval(meta);
+            let rebuilt = Eval::from_strength(result.strength()).unwrap();
+            assert_eq!(rebuilt, result);
+            assert_eq!(rebuilt.class(), result.class());
+        }
+
+        assert_eq!(Eval::from_strength(0), None);
+        assert_eq!(Eval::from_strength(7463), None);
+    }
+
+    #[test]
+    fn eval_all_in_order() {
+        let all = Eval::all().collect::<Vec<_>>();
+        assert_eq!(all.len(), 7462);
+        assert!(all.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(all
+            .iter()
+            .enumerate()
+            .all(|(i, result)| usize::from(result.strength()) == i + 1));
+    }
+
     #[test]
     fn eval_better_worse_tie() {
         // Pair of twos
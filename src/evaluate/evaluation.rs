@@ -1,41 +1,315 @@
-use std::ops::Index;
+use core::ops::Index;
 
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "std")]
 use rustc_hash::FxHashMap;
 
 use crate::{
-    evaluate::{meta::Meta, utils},
-    Card, Eval, EvalError,
+    evaluate::{hand_rank::PokerHandRank, low::DeuceSevenLow, meta::Meta, utils},
+    Card, CardSet, Eval, EvalError,
 };
 
 pub trait Evaluation {
     type Lookup: for<'a> Index<&'a i32, Output = Meta>;
     fn flush_lookup(&self) -> &Self::Lookup;
     fn unsuited_lookup(&self) -> &Self::Lookup;
+    /// The hand ranks of both lookups, laid out as in
+    /// [`LookupTable::dense_lookup`] for bulk evaluation.
+    ///
+    /// [`LookupTable::dense_lookup`]: crate::evaluate::lookup_table::LookupTable::dense_lookup
+    fn dense_lookup(&self) -> &[i16];
+    /// The metadata of both lookups, indexed by hand rank, as in
+    /// [`LookupTable::meta_lookup`].
+    ///
+    /// [`LookupTable::meta_lookup`]: crate::evaluate::lookup_table::LookupTable::meta_lookup
+    fn meta_lookup(&self) -> &[Meta];
 }
 
+#[cfg(feature = "std")]
 impl Evaluation for super::Evaluator {
     type Lookup = FxHashMap<i32, Meta>;
 
-    fn flush_lookup(&self) -> &Self::Lookup { &self.0.flush_lookup }
+    fn flush_lookup(&self) -> &Self::Lookup { &self.table.flush_lookup }
 
-    fn unsuited_lookup(&self) -> &Self::Lookup { &self.0.unsuited_lookup }
+    fn unsuited_lookup(&self) -> &Self::Lookup { &self.table.unsuited_lookup }
+
+    fn dense_lookup(&self) -> &[i16] { &self.bulk_lookup().dense }
+
+    fn meta_lookup(&self) -> &[Meta] { &self.bulk_lookup().meta }
 }
 
 pub fn evaluate(evaluator: &impl Evaluation, cards: &[Card]) -> Result<Eval, EvalError> {
+    if utils::all_unique(cards) {
+        evaluate_unique(evaluator, cards)
+    } else {
+        Err(EvalError::cards_not_unique(cards))
+    }
+}
+
+pub fn evaluate_set(evaluator: &impl Evaluation, cards: CardSet) -> Result<Eval, EvalError> {
+    // A set never holds more than 52 cards, so we can avoid allocating
+    let mut buf = [Card::from_index(0); 52];
+    let mut len = 0;
+    for card in cards {
+        buf[len] = card;
+        len += 1;
+    }
+    evaluate_unique(evaluator, &buf[..len])
+}
+
+/// Evaluate cards that are already known to be unique.
+pub fn evaluate_unique(evaluator: &impl Evaluation, cards: &[Card]) -> Result<Eval, EvalError> {
+    match cards.len() {
+        x if x < 5 => Err(EvalError::InvalidHandSize(x)),
+        5 => {
+            let cards_array = [cards[0], cards[1], cards[2], cards[3], cards[4]];
+            Ok(five(evaluator, cards_array))
+        }
+        _ => Ok(six_plus(evaluator, cards)),
+    }
+}
+
+/// The number of hands that [`evaluate_many_unique`] works on side by side.
+const LANES: usize = 16;
+
+pub fn evaluate_many<const N: usize>(
+    evaluator: &impl Evaluation,
+    hands: &[[Card; N]],
+    out: &mut [Eval],
+) -> Result<(), EvalError> {
+    // Validate everything up front, so the hot loop doesn't have to
+    if N < 5 {
+        return Err(EvalError::InvalidHandSize(N));
+    }
+    if let Some(hand) = hands.iter().find(|hand| !hand_is_unique(hand)) {
+        return Err(EvalError::cards_not_unique(hand));
+    }
+    evaluate_many_unique(evaluator, hands, out)
+}
+
+/// Whether a fixed-size hand holds unique cards, cheaply enough to check every
+/// hand before bulk evaluation.
+fn hand_is_unique<const N: usize>(hand: &[Card; N]) -> bool {
+    // A bit for every card, straight from the suit and rank bits
+    let bits = hand.iter().fold(0_u64, |bits, card| {
+        let unique = card.unique_integer();
+        bits | (unique as u64 >> 12 & 0xF) << ((unique >> 8 & 0xF) * 4)
+    });
+    bits.count_ones() as usize == N
+}
+
+/// Evaluate hands of `N` cards that are already known to be unique into `out`.
+///
+/// The hands are worked on in groups of [`LANES`], with their cards laid out
+/// card-major, so that almost every step below is a straight-line loop over
+/// the lanes of a group, which the compiler can vectorize. Rather than hashing
+/// prime products, each five-card combination is looked up by its multiset of
+/// ranks in the dense lookup, whose index is a sum of per-card offsets. Sorting
+/// the cards of each hand by rank up front means the ranks of every
+/// combination come out in order, so those offsets are known ahead of time.
+pub fn evaluate_many_unique<const N: usize>(
+    evaluator: &impl Evaluation,
+    hands: &[[Card; N]],
+    out: &mut [Eval],
+) -> Result<(), EvalError> {
+    assert_eq!(
+        hands.len(),
+        out.len(),
+        "there must be exactly one output for every hand"
+    );
+    if N < 5 {
+        return Err(EvalError::InvalidHandSize(N));
+    }
+
+    let dense_lookup = evaluator.dense_lookup();
+    let indices: [usize; N] = core::array::from_fn(|i| i);
+    for (hands, out) in hands.chunks(LANES).zip(out.chunks_mut(LANES)) {
+        let mut uniques = [[0; LANES]; N];
+        for (lane, hand) in hands.iter().enumerate() {
+            for (uniques, card) in uniques.iter_mut().zip(hand) {
+                uniques[lane] = card.unique_integer();
+            }
+        }
+        // Sort the cards of each hand by rank, which are the highest bits of
+        // the encoding. An odd-even transposition sort compares the same pair
+        // of cards in every lane at each step, without branching.
+        for round in 0..N {
+            for i in (round % 2..N - 1).step_by(2) {
+                let (lower, higher) = uniques.split_at_mut(i + 1);
+                for (a, b) in lower[i].iter_mut().zip(&mut higher[0]) {
+                    let min = (*a).min(*b);
+                    *b = (*a).max(*b);
+                    *a = min;
+                }
+            }
+        }
+
+        // What each card adds to the index when it is the `i`th lowest card of
+        // a combination, and its suit bit
+        let mut offsets = [[[0_u16; LANES]; 5]; N];
+        let mut suits = [[0_u16; LANES]; N];
+        for (card, uniques) in uniques.iter().enumerate() {
+            // The `card`th lowest card can only be the `i`th lowest of a
+            // combination if there are enough cards below and above it
+            let positions = offsets[card]
+                .iter_mut()
+                .zip(&utils::FIVE_RANK_OFFSETS)
+                .take(card.min(4) + 1)
+                .skip(card.saturating_sub(N - 5));
+            for (offsets, rank_offsets) in positions {
+                for (offset, &unique) in offsets.iter_mut().zip(uniques) {
+                    *offset = rank_offsets[(unique >> 8) as usize & 0xF];
+                }
+            }
+            for (suit, &unique) in suits[card].iter_mut().zip(uniques) {
+                *suit = (unique >> 12) as u16 & 0xF;
+            }
+        }
+
+        let mut best = [PokerHandRank::WORST.0; LANES];
+        for combo in utils::const_combos::<_, 5>(&indices) {
+            let mut dense_indices = [0_u16; LANES];
+            let mut flushes = [0xF_u16; LANES];
+            for (i, &card) in combo.iter().enumerate() {
+                let lanes = dense_indices.iter_mut().zip(&mut flushes);
+                for ((index, flush), (&offset, &suit)) in
+                    lanes.zip(offsets[card][i].iter().zip(&suits[card]))
+                {
+                    *index += offset;
+                    *flush &= suit;
+                }
+            }
+            for (index, &flush) in dense_indices.iter_mut().zip(&flushes) {
+                *index += u16::from(flush != 0) * utils::FIVE_RANK_MULTISETS as u16;
+            }
+            for (best, &index) in best.iter_mut().zip(&dense_indices) {
+                *best = (*best).min(dense_lookup[usize::from(index)]);
+            }
+        }
+
+        let meta_lookup = evaluator.meta_lookup();
+        for (out, &hand_rank) in out.iter_mut().zip(&best) {
+            *out = Eval(meta_lookup[hand_rank as usize - 1]);
+        }
+    }
+    Ok(())
+}
+
+/// The number of hands in each piece of work handed to rayon by
+/// [`par_evaluate_many_unique`].
+#[cfg(feature = "rayon")]
+const PAR_CHUNK: usize = 64 * LANES;
+
+#[cfg(feature = "rayon")]
+pub fn par_evaluate_many<const N: usize>(
+    evaluator: &(impl Evaluation + Sync),
+    hands: &[[Card; N]],
+    out: &mut [Eval],
+) -> Result<(), EvalError> {
+    if N < 5 {
+        return Err(EvalError::InvalidHandSize(N));
+    }
+    // Report the first invalid hand, as `evaluate_many` would
+    if let Some(hand) = hands.par_iter().find_first(|hand| !hand_is_unique(hand)) {
+        return Err(EvalError::cards_not_unique(hand));
+    }
+    par_evaluate_many_unique(evaluator, hands, out)
+}
+
+/// Split the hands into chunks for [`evaluate_many_unique`] to evaluate in
+/// parallel. Each result only depends on its own hand, so the output is the
+/// same no matter how the chunks are scheduled.
+#[cfg(feature = "rayon")]
+pub fn par_evaluate_many_unique<const N: usize>(
+    evaluator: &(impl Evaluation + Sync),
+    hands: &[[Card; N]],
+    out: &mut [Eval],
+) -> Result<(), EvalError> {
+    assert_eq!(
+        hands.len(),
+        out.len(),
+        "there must be exactly one output for every hand"
+    );
+    if N < 5 {
+        return Err(EvalError::InvalidHandSize(N));
+    }
+    hands
+        .par_chunks(PAR_CHUNK)
+        .zip(out.par_chunks_mut(PAR_CHUNK))
+        .try_for_each(|(hands, out)| evaluate_many_unique(evaluator, hands, out))
+}
+
+pub fn evaluate_best_hand(
+    evaluator: &impl Evaluation,
+    cards: &[Card],
+) -> Result<(Eval, [Card; 5]), EvalError> {
     if utils::all_unique(cards) {
         match cards.len() {
             x if x < 5 => Err(EvalError::InvalidHandSize(x)),
-            5 => {
-                let cards_array = [cards[0], cards[1], cards[2], cards[3], cards[4]];
-                Ok(five(evaluator, cards_array))
+            _ => {
+                let (eval, mut best) = best_five(evaluator, cards);
+                utils::sort_by_significance(&mut best, eval);
+                Ok((eval, best))
             }
-            _ => Ok(six_plus(evaluator, cards)),
         }
     } else {
-        Err(EvalError::CardsNotUnique(cards.to_vec()))
+        Err(EvalError::cards_not_unique(cards))
     }
 }
 
+/// Evaluate an Omaha hand, which must use exactly two of its hole cards and
+/// exactly three cards from the board.
+pub fn evaluate_omaha(
+    evaluator: &impl Evaluation,
+    hole_cards: &[Card],
+    board: &[Card],
+) -> Result<Eval, EvalError> {
+    if !(4..=6).contains(&hole_cards.len()) {
+        return Err(EvalError::InvalidHoleCardCount(hole_cards.len()));
+    }
+    if !(3..=5).contains(&board.len()) {
+        return Err(EvalError::InvalidBoardSize(board.len()));
+    }
+    // At most 6 hole cards and 5 board cards, so we can avoid allocating
+    let mut buf = [Card::from_index(0); 11];
+    let len = hole_cards.len() + board.len();
+    buf[..hole_cards.len()].copy_from_slice(hole_cards);
+    buf[hole_cards.len()..len].copy_from_slice(board);
+    let all_cards = &buf[..len];
+    if !utils::all_unique(all_cards) {
+        return Err(EvalError::cards_not_unique(all_cards));
+    }
+
+    let mut current_max = Eval::WORST;
+    for [first, second] in utils::const_combos::<_, 2>(hole_cards) {
+        for [third, fourth, fifth] in utils::const_combos::<_, 3>(board) {
+            let score = five(evaluator, [first, second, third, fourth, fifth]);
+            if score > current_max {
+                current_max = score;
+            }
+        }
+    }
+    Ok(current_max)
+}
+
+/// Find the best deuce-to-seven low among any five of the given cards.
+pub fn evaluate_deuce_to_seven(
+    evaluator: &impl Evaluation,
+    cards: &[Card],
+) -> Result<DeuceSevenLow, EvalError> {
+    if !utils::all_unique(cards) {
+        return Err(EvalError::cards_not_unique(cards));
+    }
+    if cards.len() < 5 {
+        return Err(EvalError::InvalidHandSize(cards.len()));
+    }
+    Ok(utils::const_combos::<_, 5>(cards)
+        .map(|hand| DeuceSevenLow::from_eval(five(evaluator, hand)))
+        .max()
+        .expect("there is always at least one combination"))
+}
+
 fn five(evaluator: &impl Evaluation, cards: [Card; 5]) -> Eval {
     let uniques = cards.map(Card::unique_integer);
 
@@ -63,3 +337,21 @@ fn six_plus(evaluator: &impl Evaluation, cards: &[Card]) -> Eval {
     }
     current_max
 }
+
+/// Like [`six_plus`], but also keep track of which five cards made the best
+/// hand. Works for a hand of exactly five cards, too.
+fn best_five(evaluator: &impl Evaluation, cards: &[Card]) -> (Eval, [Card; 5]) {
+    debug_assert!(cards.len() >= 5);
+    let mut all_five_card_combos = utils::const_combos::<_, 5>(cards);
+    // There is always at least one combination
+    let mut best_cards = all_five_card_combos.next().unwrap();
+    let mut current_max = five(evaluator, best_cards);
+    for combo in all_five_card_combos {
+        let score = five(evaluator, combo);
+        if score > current_max {
+            current_max = score;
+            best_cards = combo;
+        }
+    }
+    (current_max, best_cards)
+}
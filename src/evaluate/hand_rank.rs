@@ -1,5 +1,3 @@
-use crate::evaluate::lookup_table;
-
 /// The rank of a hand, ranging from 1 (best) to 7462 (worst).
 /// This is not to be confused with a card rank! This number is mainly
 /// used internally to compare hands easily using integer values (if one hand
@@ -9,10 +7,42 @@ pub struct PokerHandRank(pub(crate) i16);
 
 impl PokerHandRank {
     pub const BEST: Self = Self(1);
-    pub const WORST: Self = Self(lookup_table::constants::WORST_HIGH_CARD as i16);
+    pub const WORST: Self = Self(constants::WORST_HIGH_CARD);
 
     /// Use this rather than Ord, because < meaning better can be confusing.
     pub const fn is_better_than(self, other: Self) -> bool { self.0 < other.0 }
 
     pub const fn is_worse_than(self, other: Self) -> bool { self.0 > other.0 }
 }
+
+pub mod constants {
+
+    // These are the worst hand ranks for each of the poker hands
+
+    pub const WORST_STRAIGHT_FLUSH: i16 = 10;
+    pub const WORST_FOUR_OF_A_KIND: i16 = 166;
+    pub const WORST_FULL_HOUSE: i16 = 322;
+    pub const WORST_FLUSH: i16 = 1599;
+    pub const WORST_STRAIGHT: i16 = 1609;
+    pub const WORST_THREE_OF_A_KIND: i16 = 2467;
+    pub const WORST_TWO_PAIR: i16 = 3325;
+    pub const WORST_PAIR: i16 = 6185;
+    pub const WORST_HIGH_CARD: i16 = 7462;
+
+    // The number of distinct ace-to-five lows, which is also the worst of them
+    pub const WORST_ACE_FIVE_LOW: u16 = 6175;
+
+    /// Statically calculated bit straights
+    pub const STRAIGHTS: [i16; 10] = [
+        0b1_1111_0000_0000, // 7936 => TJQKA
+        0b0_1111_1000_0000, // 3968 => 9TJQK
+        0b0_0111_1100_0000, // 1984 => 89TJQ
+        0b0_0011_1110_0000, // 992 => 789TJ
+        0b0_0001_1111_0000, // 496 => 6789T
+        0b0_0000_1111_1000, // 248 => 56789
+        0b0_0000_0111_1100, // 124 => 45678
+        0b0_0000_0011_1110, // 62 => 34567
+        0b0_0000_0001_1111, // 31 => 23456
+        0b1_0000_0000_1111, // 4111 => A2345
+    ];
+}
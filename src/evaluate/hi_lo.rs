@@ -0,0 +1,229 @@
+use std::fmt;
+
+use crate::{AceFiveLow, Eval};
+
+/// The result of evaluating a hand for a Hi-Lo split pot game with an
+/// "eight-or-better" low, such as Omaha-8 or Stud-8.
+///
+/// Half of the pot goes to the best high hand, and the other half goes to the
+/// best qualifying low, if any player has one. A hand may win both halves,
+/// which is known as scooping. Use [`HiLo::split_pot`] to divide a pot among
+/// several players.
+///
+/// # Example
+///
+/// ```
+/// use poker::{cards, Card, Evaluator};
+///
+/// let eval = Evaluator::new();
+/// let hand: Vec<Card> = cards!("As 2s 3d 4c 5h 9h Kd")
+///     .try_collect()
+///     .expect("couldn't parse cards");
+///
+/// let result = eval
+///     .evaluate_stud_hi_lo(hand)
+///     .expect("couldn't evaluate hand");
+/// assert!(result.high().is_straight());
+/// assert_eq!(
+///     result.low().map(|low| low.to_string()),
+///     Some("5-4-3-2-A low".into())
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct HiLo {
+    high: Eval,
+    low: Option<AceFiveLow>,
+}
+
+impl HiLo {
+    /// Combine a high hand and an optional qualifying low into a [`HiLo`]
+    /// result. Lows that don't qualify as eight or better are discarded.
+    pub fn new(high: Eval, low: Option<AceFiveLow>) -> Self {
+        Self {
+            high,
+            low: low.filter(|low| low.is_eight_or_better()),
+        }
+    }
+
+    /// The best high hand.
+    pub const fn high(self) -> Eval { self.high }
+
+    /// The best eight-or-better low, or `None` if the hand doesn't have one.
+    pub const fn low(self) -> Option<AceFiveLow> { self.low }
+
+    /// Divide a pot of `pot` chips among the players whose hands are given,
+    /// returning how many chips each player wins, in the same order.
+    ///
+    /// If nobody has a qualifying low, the best high hand (or hands, if tied)
+    /// wins the whole pot. Otherwise, the pot is split in half between the
+    /// best high and the best low, and each half is split evenly between the
+    /// players who tie for it. A player who ties for one half and doesn't win
+    /// the other may win only a quarter of the pot, or less.
+    ///
+    /// Chips that can't be split evenly are handed out one at a time: when
+    /// the pot can't be halved evenly, the odd chip goes to the high half, and
+    /// when a half can't be split evenly between tied players, the odd chips go
+    /// to the winners that appear first in `hands`. Pass the hands in seat
+    /// order, starting from the player to the left of the button, to follow
+    /// the usual rule for awarding odd chips.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{cards, Card, Evaluator, HiLo};
+    ///
+    /// let eval = Evaluator::new();
+    /// let board: Vec<Card> = cards!("2c 3d 7h Kd Ks")
+    ///     .try_collect()
+    ///     .expect("couldn't parse cards");
+    /// let hands: Vec<HiLo> = ["Ah 4h Qc Jc", "Ad 4d Qs Js", "Kh Kc 9s 9d"]
+    ///     .iter()
+    ///     .map(|hole_cards| {
+    ///         let hole_cards: Vec<Card> = Card::parse_to_iter(hole_cards.split_whitespace())
+    ///             .try_collect()
+    ///             .expect("couldn't parse cards");
+    ///         eval.evaluate_omaha_hi_lo(hole_cards, &board)
+    ///             .expect("couldn't evaluate hand")
+    ///     })
+    ///     .collect();
+    ///
+    /// // Quads take the high half, and the two identical lows are quartered
+    /// assert_eq!(HiLo::split_pot(101, &hands), vec![25, 25, 51]);
+    /// ```
+    pub fn split_pot(pot: u64, hands: &[Self]) -> Vec<u64> {
+        let mut shares = vec![0; hands.len()];
+        let best_high = match hands.iter().map(|hand| hand.high).max() {
+            Some(best) => best,
+            None => return shares,
+        };
+        let high_winners = hands.iter().map(|hand| hand.high == best_high);
+
+        match hands.iter().filter_map(|hand| hand.low).max() {
+            None => award(pot, high_winners, &mut shares),
+            Some(best_low) => {
+                let low_half = pot / 2;
+                award(pot - low_half, high_winners, &mut shares);
+                let low_winners = hands.iter().map(|hand| hand.low == Some(best_low));
+                award(low_half, low_winners, &mut shares);
+            }
+        }
+        shares
+    }
+}
+
+/// Split `chips` evenly between the winners, handing any odd chips to the
+/// earliest winners.
+fn award(chips: u64, winners: impl Iterator<Item = bool> + Clone, shares: &mut [u64]) {
+    let count = winners.clone().filter(|&won| won).count() as u64;
+    let (each, mut odd_chips) = (chips / count, chips % count);
+    for (share, won) in shares.iter_mut().zip(winners) {
+        if won {
+            *share += each;
+            if odd_chips > 0 {
+                *share += 1;
+                odd_chips -= 1;
+            }
+        }
+    }
+}
+
+impl fmt::Display for HiLo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.low {
+            Some(low) => write!(f, "{} / {}", self.high, low),
+            None => write!(f, "{} / no low", self.high),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{evaluate::tests::EVALUATOR, Card, EvalError};
+
+    fn parse(cards: &str) -> Vec<Card> {
+        Card::parse_to_iter(cards.split_whitespace())
+            .try_collect()
+            .unwrap()
+    }
+
+    fn omaha(hole_cards: &str, board: &str) -> HiLo {
+        EVALUATOR
+            .evaluate_omaha_hi_lo(parse(hole_cards), parse(board))
+            .unwrap()
+    }
+
+    #[test]
+    fn omaha_halves_are_chosen_independently() {
+        // The high uses the kings, the low uses the ace and deuce
+        let result = omaha("Kc Kd Ah 2h", "Ks 3c 4d 8s Qh");
+        assert!(result.high().is_three_of_a_kind());
+        assert_eq!(result.low().unwrap().to_string(), "8-4-3-2-A low");
+
+        // Only two hole cards may be used for the low, too
+        assert_eq!(omaha("Ac Kd Qh Js", "2c 3d 4h 5s 6c").low(), None);
+        // Three board cards must be low enough to qualify
+        assert_eq!(omaha("Ac 2d Qh Js", "3c 4d 9h Ts Kc").low(), None);
+    }
+
+    #[test]
+    fn stud_hi_lo() {
+        let result = EVALUATOR
+            .evaluate_stud_hi_lo(parse("Ac Ad 9h 7s 4c 3d 2h"))
+            .unwrap();
+        assert!(result.high().is_pair());
+        assert_eq!(result.low().unwrap().to_string(), "7-4-3-2-A low");
+        assert_eq!(result.to_string(), "Pair, aces / 7-4-3-2-A low");
+
+        let result = EVALUATOR
+            .evaluate_stud_hi_lo(parse("Kc Kd 9h 9s 4c 3d 2h"))
+            .unwrap();
+        assert_eq!(result.low(), None);
+        assert_eq!(result.to_string(), "Two pair, kings and nines / no low");
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        assert_eq!(
+            EVALUATOR.evaluate_omaha_hi_lo(parse("Ac Kd Qh"), parse("2c 3d 4h")),
+            Err(EvalError::InvalidHoleCardCount(3))
+        );
+        assert_eq!(
+            EVALUATOR.evaluate_stud_hi_lo(parse("Ac Kd Qh 2c")),
+            Err(EvalError::InvalidHandSize(4))
+        );
+    }
+
+    #[test]
+    fn scoop_without_low() {
+        let board = "Kc Qd 9h 9s Tc";
+        let hands = [omaha("Ac Jd 2h 3s", board), omaha("9c 9d 4h 5s", board)];
+        assert_eq!(HiLo::split_pot(100, &hands), vec![0, 100]);
+    }
+
+    #[test]
+    fn split_and_quarter() {
+        let board = "2c 3d 7h Kd Ks";
+        let high = omaha("Kh Kc 9s 9d", board);
+        let low = omaha("Ah 4h Qc Jc", board);
+        let same_low = omaha("Ad 4d Qs Js", board);
+        let scoop = omaha("Ac 4c Kh Qh", board);
+
+        assert_eq!(HiLo::split_pot(100, &[high, low]), vec![50, 50]);
+        // The odd chip goes to the high half
+        assert_eq!(HiLo::split_pot(101, &[low, high]), vec![50, 51]);
+        // Quartered: each low gets a quarter, and the odd chip goes to the
+        // earlier of them
+        assert_eq!(
+            HiLo::split_pot(102, &[low, same_low, high]),
+            vec![26, 25, 51]
+        );
+        assert_eq!(
+            HiLo::split_pot(102, &[same_low, low, high]),
+            vec![26, 25, 51]
+        );
+        // A player who wins the high and ties the low gets three quarters
+        assert_eq!(HiLo::split_pot(100, &[scoop, low]), vec![75, 25]);
+        assert_eq!(HiLo::split_pot(100, &[]), Vec::<u64>::new());
+    }
+}
@@ -1,10 +1,13 @@
 use rustc_hash::{FxBuildHasher, FxHashMap};
 
-use self::constants::*;
 use crate::{
     card::rank::Rank,
     constants::{INT_RANKS, PRIMES},
-    evaluate::{hand_rank::PokerHandRank, meta::Meta, utils},
+    evaluate::{
+        hand_rank::{constants::*, PokerHandRank},
+        meta::Meta,
+        utils,
+    },
 };
 
 /// Stores information about looking up poker hands.
@@ -35,6 +38,48 @@ impl LookupTable {
         table
     }
 
+    /// Lay out the hand ranks of both tables in a single array, indexed by a
+    /// hand's multiset of ranks (see [`utils::five_rank_index`]) rather than
+    /// its prime product. The unsuited hands come first, then the flushes,
+    /// offset by [`utils::FIVE_RANK_MULTISETS`]. Multisets that can't be a hand
+    /// of that kind are left as the worst hand rank.
+    pub fn dense_lookup(&self) -> Vec<i16> {
+        let mut dense = vec![PokerHandRank::WORST.0; 2 * utils::FIVE_RANK_MULTISETS];
+        let tables = [&self.unsuited_lookup, &self.flush_lookup];
+        for (offset, table) in tables.into_iter().enumerate() {
+            for (&product, meta) in table {
+                // Factor the prime product back into its ranks, lowest first
+                let mut ranks = [0; 5];
+                let mut product = product;
+                let mut i = 0;
+                for (rank, &prime) in PRIMES.iter().enumerate() {
+                    while product % prime == 0 {
+                        ranks[i] = rank;
+                        product /= prime;
+                        i += 1;
+                    }
+                }
+                let index = offset * utils::FIVE_RANK_MULTISETS + utils::five_rank_index(ranks);
+                dense[index] = meta.hand_rank().0;
+            }
+        }
+        dense
+    }
+
+    /// Collect the metadata of both tables, indexed by hand rank, starting from
+    /// the best hand at index 0.
+    pub fn meta_lookup(&self) -> Vec<Meta> {
+        let mut metas = vec![Meta::WORST; PokerHandRank::WORST.0 as usize];
+        for &meta in self
+            .flush_lookup
+            .values()
+            .chain(self.unsuited_lookup.values())
+        {
+            metas[meta.hand_rank().0 as usize - 1] = meta;
+        }
+        metas
+    }
+
     /// Calculate the metadata for flushes, straights, high cards, and straight
     /// flushes.
     fn flushes_straights_high_cards(&mut self) {
@@ -326,35 +371,6 @@ impl LookupTable {
     }
 }
 
-pub mod constants {
-
-    // These are the worst hand ranks for each of the poker hands
-
-    pub const WORST_STRAIGHT_FLUSH: i16 = 10;
-    pub const WORST_FOUR_OF_A_KIND: i16 = 166;
-    pub const WORST_FULL_HOUSE: i16 = 322;
-    pub const WORST_FLUSH: i16 = 1599;
-    pub const WORST_STRAIGHT: i16 = 1609;
-    pub const WORST_THREE_OF_A_KIND: i16 = 2467;
-    pub const WORST_TWO_PAIR: i16 = 3325;
-    pub const WORST_PAIR: i16 = 6185;
-    pub const WORST_HIGH_CARD: i16 = 7462;
-
-    /// Statically calculated bit straights
-    pub const STRAIGHTS: [i16; 10] = [
-        0b1_1111_0000_0000, // 7936 => TJQKA
-        0b0_1111_1000_0000, // 3968 => 9TJQK
-        0b0_0111_1100_0000, // 1984 => 89TJQ
-        0b0_0011_1110_0000, // 992 => 789TJ
-        0b0_0001_1111_0000, // 496 => 6789T
-        0b0_0000_1111_1000, // 248 => 56789
-        0b0_0000_0111_1100, // 124 => 45678
-        0b0_0000_0011_1110, // 62 => 34567
-        0b0_0000_0001_1111, // 31 => 23456
-        0b1_0000_0000_1111, // 4111 => A2345
-    ];
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
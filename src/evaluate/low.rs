@@ -0,0 +1,567 @@
+use core::{cmp::Ordering, fmt};
+
+#[cfg(feature = "std")]
+use rustc_hash::{FxBuildHasher, FxHashMap};
+
+#[cfg(feature = "std")]
+use crate::{constants::PRIMES, evaluate::utils, Card, EvalError};
+use crate::{
+    evaluate::hand_rank::constants::{WORST_ACE_FIVE_LOW, WORST_FLUSH, WORST_HIGH_CARD},
+    Eval, Rank,
+};
+
+/// The result of evaluating a hand for ace-to-five low, as played in Razz and
+/// for the low half of Omaha and Stud Hi-Lo.
+///
+/// In ace-to-five low, aces always play low, and straights and flushes don't
+/// count against a hand, so the best possible low is 5-4-3-2-A, known as the
+/// wheel. Hands without a pair are compared by their highest card, then their
+/// next-highest, and so on. Any unpaired hand beats any paired hand, which in
+/// turn beats any hand with two pair, trips, a full house, or quads.
+///
+/// Like [`Eval`], instances of `AceFiveLow` implement [`Ord`] such that better
+/// lows are greater. When printed in [`Display`](fmt::Display) format, the
+/// ranks are listed from highest to lowest, such as "7-5-4-3-A low".
+///
+/// # Example
+///
+/// ```
+/// use poker::{cards, Card, Evaluator};
+///
+/// let eval = Evaluator::new();
+/// let seven: Vec<Card> = cards!("7c 5d 4h 3s Ad")
+///     .try_collect()
+///     .expect("couldn't parse cards");
+/// let eight: Vec<Card> = cards!("8c 4d 3h 2s Ad")
+///     .try_collect()
+///     .expect("couldn't parse cards");
+///
+/// let seven = eval
+///     .evaluate_ace_to_five(seven)
+///     .expect("couldn't evaluate hand");
+/// let eight = eval
+///     .evaluate_ace_to_five(eight)
+///     .expect("couldn't evaluate hand");
+/// assert_eq!(seven.to_string(), "7-5-4-3-A low");
+/// assert!(seven > eight);
+/// assert!(eight.is_eight_or_better());
+/// ```
+///
+/// [`Eval`]: crate::Eval
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct AceFiveLow {
+    // 1 is the wheel, and `WORST_ACE_FIVE_LOW` is four kings with a queen
+    ordinal: u16,
+    // Ordered from highest to lowest, with aces last
+    ranks: [Rank; 5],
+}
+
+impl AceFiveLow {
+    /// The best possible ace-to-five low, 5-4-3-2-A.
+    pub const BEST: Self = Self {
+        ordinal: 1,
+        ranks: [Rank::Five, Rank::Four, Rank::Three, Rank::Two, Rank::Ace],
+    };
+
+    #[cfg(feature = "std")]
+    pub(crate) const fn new(ordinal: u16, ranks: [Rank; 5]) -> Self { Self { ordinal, ranks } }
+
+    /// The ranks of the five cards that make up this low, from highest to
+    /// lowest. Aces are low, so they always come last.
+    pub const fn ranks(self) -> [Rank; 5] { self.ranks }
+
+    /// Check whether this low qualifies for the low half of the pot in an
+    /// "eight-or-better" Hi-Lo game, meaning it has no pairs and no card higher
+    /// than an eight.
+    pub const fn is_eight_or_better(self) -> bool { self.ordinal <= EIGHT_OR_BETTER_LOWS }
+
+    /// A number representing the strength of this low, where higher is better.
+    /// The weakest low, four kings with a queen, has a strength of 1, and the
+    /// wheel has a strength of 6175.
+    pub const fn strength(self) -> u16 { WORST_ACE_FIVE_LOW + 1 - self.ordinal }
+}
+
+/// There are 56 ways to choose five different ranks from ace through eight,
+/// and these are the 56 best lows.
+const EIGHT_OR_BETTER_LOWS: u16 = 56;
+
+impl PartialOrd for AceFiveLow {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for AceFiveLow {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // A lower ordinal is a better low
+        other.ordinal.cmp(&self.ordinal)
+    }
+}
+
+impl fmt::Display for AceFiveLow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, rank) in self.ranks.iter().enumerate() {
+            if i > 0 {
+                f.write_str("-")?;
+            }
+            write!(f, "{}", rank)?;
+        }
+        f.write_str(" low")
+    }
+}
+
+/// Calculate ace-to-five lows for every multiset of five ranks, keyed by their
+/// prime product. Suits never matter for these, so a hand's prime product is
+/// all we need.
+#[cfg(feature = "std")]
+pub(crate) fn ace_five_lookup() -> FxHashMap<i32, AceFiveLow> {
+    // Aces play low, so we work with "low values" where an ace is 0, a deuce
+    // is 1, and so on up to a king, which is 12
+    let rank_from_low_value = |value: u8| match value {
+        0 => Rank::Ace,
+        _ => Rank::ALL_VARIANTS[usize::from(value) - 1],
+    };
+
+    // Every hand's low values from highest to lowest, along with a key that
+    // sorts from best low to worst
+    let mut lows = Vec::with_capacity(WORST_ACE_FIVE_LOW as usize);
+    for a in 0..13 {
+        for b in a..13 {
+            for c in b..13 {
+                for d in c..13 {
+                    for e in d..13 {
+                        // Five of a kind is impossible
+                        if a != e {
+                            let values = [e, d, c, b, a];
+                            lows.push((ace_five_key(values), values));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    lows.sort_unstable_by_key(|&(key, _)| key);
+    debug_assert_eq!(lows.len(), WORST_ACE_FIVE_LOW as usize);
+
+    let mut lookup =
+        FxHashMap::with_capacity_and_hasher(WORST_ACE_FIVE_LOW as usize, FxBuildHasher);
+    for (ordinal, (_, values)) in (1..).zip(lows) {
+        let ranks = values.map(rank_from_low_value);
+        let product = ranks
+            .iter()
+            .fold(1, |acc, &rank| acc * PRIMES[rank as usize]);
+        lookup.insert(product, AceFiveLow::new(ordinal, ranks));
+    }
+    lookup
+}
+
+/// Build a key for the ace-to-five low with the given low values, ordered from
+/// highest to lowest, such that better lows have smaller keys. Hands are
+/// compared first by their shape (no pair, one pair, two pair, trips, full
+/// house, quads), then by their low values in order of significance.
+#[cfg(feature = "std")]
+fn ace_five_key(values: [u8; 5]) -> u32 {
+    // Group matching values as (size, value), then order the groups with the
+    // largest first, breaking ties with the highest value first
+    let mut groups = [(0_u8, 0_u8); 5];
+    let mut len = 0;
+    for value in values {
+        if len > 0 && groups[len - 1].1 == value {
+            groups[len - 1].0 += 1;
+        } else {
+            groups[len] = (1, value);
+            len += 1;
+        }
+    }
+    let groups = &mut groups[..len];
+    groups.sort_unstable_by(|a, b| b.cmp(a));
+
+    let shape = match (groups[0].0, groups[1].0) {
+        (1, _) => 0,
+        (2, 1) => 1,
+        (2, 2) => 2,
+        (3, 1) => 3,
+        (3, 2) => 4,
+        _ => 5,
+    };
+    groups.iter().fold(shape, |key, &(size, value)| {
+        (0..size).fold(key, |key, _| key * 13 + u32::from(value))
+    })
+}
+
+/// Find the best ace-to-five low among any five of the given cards.
+#[cfg(feature = "std")]
+pub(crate) fn evaluate_ace_to_five(
+    lookup: &FxHashMap<i32, AceFiveLow>,
+    cards: &[Card],
+) -> Result<AceFiveLow, EvalError> {
+    if !utils::all_unique(cards) {
+        return Err(EvalError::cards_not_unique(cards));
+    }
+    if cards.len() < 5 {
+        return Err(EvalError::InvalidHandSize(cards.len()));
+    }
+    Ok(best_ace_to_five(lookup, cards))
+}
+
+/// Like [`evaluate_ace_to_five`], for cards already known to be unique and to
+/// number at least five.
+#[cfg(feature = "std")]
+pub(crate) fn best_ace_to_five(lookup: &FxHashMap<i32, AceFiveLow>, cards: &[Card]) -> AceFiveLow {
+    debug_assert!(cards.len() >= 5);
+    utils::const_combos::<_, 5>(cards)
+        .map(|hand| lookup[&utils::prime_product_from_hand(hand)])
+        .max()
+        .expect("there is always at least one combination")
+}
+
+/// The result of evaluating a hand for deuce-to-seven low, as played in 2-7
+/// Triple Draw and 2-7 Single Draw.
+///
+/// Deuce-to-seven low ranks hands in almost exactly the opposite order of
+/// [`Eval`]: aces are always high, and straights and flushes count against a
+/// hand, so the best possible low is 7-5-4-3-2 in at least two suits. The one
+/// exception is A-5-4-3-2, which is not a straight in deuce-to-seven, so it
+/// ranks as the best ace-high hand (or the best ace-high flush, if suited)
+/// rather than as a straight.
+///
+/// Like [`Eval`], instances of `DeuceSevenLow` implement [`Ord`] such that
+/// better lows are greater. When printed in [`Display`](fmt::Display) format,
+/// the ranks are listed in order of significance, followed by "low" for hands
+/// with no pair, straight, or flush, such as "8-6-5-4-2 low", or by the kind of
+/// hand otherwise, such as "K-K-9-6-4 pair".
+///
+/// # Example
+///
+/// ```
+/// use poker::{cards, Card, Evaluator};
+///
+/// let eval = Evaluator::new();
+/// let number_one: Vec<Card> = cards!("7c 5d 4h 3s 2d")
+///     .try_collect()
+///     .expect("couldn't parse cards");
+/// let wheel: Vec<Card> = cards!("Ac 5d 4h 3s 2d")
+///     .try_collect()
+///     .expect("couldn't parse cards");
+/// let straight: Vec<Card> = cards!("6c 5d 4h 3s 2d")
+///     .try_collect()
+///     .expect("couldn't parse cards");
+///
+/// let number_one = eval
+///     .evaluate_deuce_to_seven(number_one)
+///     .expect("couldn't evaluate hand");
+/// let wheel = eval
+///     .evaluate_deuce_to_seven(wheel)
+///     .expect("couldn't evaluate hand");
+/// let straight = eval
+///     .evaluate_deuce_to_seven(straight)
+///     .expect("couldn't evaluate hand");
+/// assert_eq!(number_one.to_string(), "7-5-4-3-2 low");
+/// assert_eq!(wheel.to_string(), "A-5-4-3-2 low");
+/// assert_eq!(straight.to_string(), "6-5-4-3-2 straight");
+/// assert!(number_one > wheel && wheel > straight);
+/// ```
+///
+/// [`Eval`]: crate::Eval
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DeuceSevenLow {
+    // Twice the high hand rank, so that A-5-4-3-2 can slot in between the hand
+    // ranks of two other hands. Higher is a better low.
+    key: u16,
+    eval: Eval,
+}
+
+/// The hand ranks of the weakest ace-high hands without a straight, A-6-4-3-2
+/// unsuited and suited. There are 784 sets of five different ranks from deuce
+/// through king that don't make a straight, all of which rank below these.
+const WORST_ACE_HIGH: i16 = WORST_HIGH_CARD - 784;
+const WORST_ACE_HIGH_FLUSH: i16 = WORST_FLUSH - 784;
+
+impl DeuceSevenLow {
+    /// Rank the five cards behind a high hand evaluation for deuce-to-seven
+    /// low.
+    pub(crate) fn from_eval(eval: Eval) -> Self {
+        let hand_rank = eval.hand_rank().0;
+        let key = match eval.ranks()[0] {
+            // A five-high straight is an ace-high hand here, just below
+            // A-6-4-3-2 in high hand terms, which makes it a better low
+            Rank::Five if eval.is_straight() => WORST_ACE_HIGH * 2 + 1,
+            Rank::Five if eval.is_straight_flush() => WORST_ACE_HIGH_FLUSH * 2 + 1,
+            _ => hand_rank * 2,
+        };
+        Self {
+            key: key as u16,
+            eval,
+        }
+    }
+
+    /// The ranks of the five cards that make up this low, ordered by
+    /// significance like [`Eval::ranks`]. Aces are high, so in A-5-4-3-2 the
+    /// ace comes first.
+    ///
+    /// [`Eval::ranks`]: crate::Eval::ranks
+    pub fn ranks(self) -> [Rank; 5] {
+        let mut ranks = self.eval.ranks();
+        if self.is_wheel() {
+            ranks.rotate_right(1);
+        }
+        ranks
+    }
+
+    /// Check whether this hand has no pair, straight, or flush, the only kind
+    /// of hand that makes a true deuce-to-seven low.
+    pub fn is_no_pair(self) -> bool {
+        self.eval.is_high_card() || (self.is_wheel() && self.eval.is_straight())
+    }
+
+    fn is_wheel(self) -> bool {
+        (self.eval.is_straight() || self.eval.is_straight_flush())
+            && self.eval.ranks()[0] == Rank::Five
+    }
+
+    fn kind(self) -> &'static str {
+        let eval = self.eval;
+        if self.is_no_pair() {
+            "low"
+        } else if eval.is_pair() {
+            "pair"
+        } else if eval.is_two_pair() {
+            "two pair"
+        } else if eval.is_three_of_a_kind() {
+            "three of a kind"
+        } else if eval.is_straight() {
+            "straight"
+        } else if eval.is_flush() || self.is_wheel() {
+            "flush"
+        } else if eval.is_full_house() {
+            "full house"
+        } else if eval.is_four_of_a_kind() {
+            "four of a kind"
+        } else {
+            "straight flush"
+        }
+    }
+}
+
+impl PartialOrd for DeuceSevenLow {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for DeuceSevenLow {
+    fn cmp(&self, other: &Self) -> Ordering { self.key.cmp(&other.key) }
+}
+
+impl fmt::Display for DeuceSevenLow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, rank) in self.ranks().iter().enumerate() {
+            if i > 0 {
+                f.write_str("-")?;
+            }
+            write!(f, "{}", rank)?;
+        }
+        write!(f, " {}", self.kind())
+    }
+}
+
+/// Find the best eight-or-better low that uses exactly two of the hole cards
+/// and exactly three cards from the board, for cards already known to be
+/// unique.
+#[cfg(feature = "std")]
+pub(crate) fn omaha_eight_or_better(
+    lookup: &FxHashMap<i32, AceFiveLow>,
+    hole_cards: &[Card],
+    board: &[Card],
+) -> Option<AceFiveLow> {
+    let mut best = None;
+    for [first, second] in utils::const_combos::<_, 2>(hole_cards) {
+        for [third, fourth, fifth] in utils::const_combos::<_, 3>(board) {
+            let hand = [first, second, third, fourth, fifth];
+            let low = lookup[&utils::prime_product_from_hand(hand)];
+            if low.is_eight_or_better() && Some(low) > best {
+                best = Some(low);
+            }
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{deck, evaluate::tests::EVALUATOR, CardSet};
+
+    fn low(cards: &str) -> AceFiveLow {
+        let cards = Card::parse_to_iter(cards.split_whitespace())
+            .try_collect::<Vec<_>>()
+            .unwrap();
+        EVALUATOR.evaluate_ace_to_five(cards).unwrap()
+    }
+
+    #[test]
+    fn ace_to_five_ordering() {
+        let ordered = [
+            "5c 4d 3h 2s Ac",
+            "6c 4d 3h 2s Ac",
+            "6c 5d 4h 3s 2c",
+            "7c 4d 3h 2s Ac",
+            "8c 7d 6h 5s 4c",
+            "9c 4d 3h 2s Ac",
+            "Kc Qd Jh Ts 9c",
+            "Ac Ad 2h 3s 4c",
+            "2c 2d 3h 4s 5c",
+            "Kc Kd Qh Js Tc",
+            "Ac Ad 2h 2s 3c",
+            "Ac Ad Ah 2s 3c",
+            "Ac Ad Ah 2s 2c",
+            "Ac Ad Ah As 2c",
+            "Kc Kd Kh Ks Qc",
+        ];
+        let lows = ordered.iter().map(|hand| low(hand)).collect::<Vec<_>>();
+        assert_eq!(lows[0], AceFiveLow::BEST);
+        assert_eq!(lows[lows.len() - 1].strength(), 1);
+        assert!(lows.windows(2).all(|pair| pair[0] > pair[1]));
+    }
+
+    #[test]
+    fn straights_and_flushes_dont_count() {
+        assert_eq!(low("5h 4h 3h 2h Ah"), AceFiveLow::BEST);
+        assert_eq!(low("8c 7c 6c 5c 4c"), low("8d 7c 6h 5s 4c"));
+    }
+
+    #[test]
+    fn best_five_of_seven() {
+        // Razz: the pairs are ignored, and the best five ranks play
+        let result = low("Kc 7d 7h 4s 2c 2d Ac");
+        assert_eq!(result.to_string(), "K-7-4-2-A low");
+        assert_eq!(low("9c 8d 6h 4s 3c 2d Ac").to_string(), "6-4-3-2-A low");
+        assert_eq!(low("Kc Kd Kh 4s 4c 4d Qc").to_string(), "K-K-Q-4-4 low");
+    }
+
+    #[test]
+    fn eight_or_better_qualifier() {
+        assert!(low("8c 7d 6h 5s 4c").is_eight_or_better());
+        assert!(low("8c 4d 3h 2s Ac").is_eight_or_better());
+        assert!(!low("9c 4d 3h 2s Ac").is_eight_or_better());
+        assert!(!low("Ac Ad 2h 3s 4c").is_eight_or_better());
+
+        let hand = Card::parse_to_iter("9c 9d Kh Qs Jc 2d 3c".split_whitespace())
+            .try_collect::<Vec<_>>()
+            .unwrap();
+        assert_eq!(EVALUATOR.evaluate_eight_or_better(&hand), Ok(None));
+        let hand = Card::parse_to_iter("8c 9d Kh 5s 4c 2d 3c".split_whitespace())
+            .try_collect::<Vec<_>>()
+            .unwrap();
+        assert_eq!(
+            EVALUATOR
+                .evaluate_eight_or_better(&hand)
+                .unwrap()
+                .map(|low| low.to_string()),
+            Some("8-5-4-3-2 low".to_string())
+        );
+    }
+
+    #[test]
+    fn count_eight_or_better_hands() {
+        let deck = deck::generate().collect::<Vec<_>>();
+        let qualifying = utils::const_combos::<_, 5>(&deck)
+            .map(|hand| EVALUATOR.evaluate_ace_to_five(hand).unwrap())
+            .filter(|low| low.is_eight_or_better())
+            .count();
+        // 56 sets of ranks, with 4^5 suits each
+        assert_eq!(qualifying, 56 * 1024);
+    }
+
+    fn deuce_seven(cards: &str) -> DeuceSevenLow {
+        let cards = Card::parse_to_iter(cards.split_whitespace())
+            .try_collect::<Vec<_>>()
+            .unwrap();
+        EVALUATOR.evaluate_deuce_to_seven(cards).unwrap()
+    }
+
+    #[test]
+    fn deuce_to_seven_ordering() {
+        let ordered = [
+            ("7c 5d 4h 3s 2c", "7-5-4-3-2 low"),
+            ("7c 6d 4h 3s 2c", "7-6-4-3-2 low"),
+            ("8c 5d 4h 3s 2c", "8-5-4-3-2 low"),
+            ("Kc Qd Jh Ts 8c", "K-Q-J-T-8 low"),
+            ("Ac 5d 4h 3s 2c", "A-5-4-3-2 low"),
+            ("Ac 6d 4h 3s 2c", "A-6-4-3-2 low"),
+            ("Ac Kd Qh Js 9c", "A-K-Q-J-9 low"),
+            ("2c 2d 3h 4s 5c", "2-2-5-4-3 pair"),
+            ("Ac Ad Kh Qs Jc", "A-A-K-Q-J pair"),
+            ("3c 3d 2h 2s 4c", "3-3-2-2-4 two pair"),
+            ("2c 2d 2h 3s 4c", "2-2-2-4-3 three of a kind"),
+            ("6c 5d 4h 3s 2c", "6-5-4-3-2 straight"),
+            ("Ac Kd Qh Js Tc", "A-K-Q-J-T straight"),
+            ("7c 5c 4c 3c 2c", "7-5-4-3-2 flush"),
+            ("Ac 5c 4c 3c 2c", "A-5-4-3-2 flush"),
+            ("Ac 6c 4c 3c 2c", "A-6-4-3-2 flush"),
+            ("2c 2d 2h 3s 3c", "2-2-2-3-3 full house"),
+            ("2c 2d 2h 2s 3c", "2-2-2-2-3 four of a kind"),
+            ("6c 5c 4c 3c 2c", "6-5-4-3-2 straight flush"),
+            ("Ac Kc Qc Jc Tc", "A-K-Q-J-T straight flush"),
+        ];
+        let lows = ordered
+            .iter()
+            .map(|&(hand, name)| {
+                let low = deuce_seven(hand);
+                assert_eq!(low.to_string(), name);
+                low
+            })
+            .collect::<Vec<_>>();
+        assert!(lows.windows(2).all(|pair| pair[0] > pair[1]));
+    }
+
+    #[test]
+    fn deuce_to_seven_reverses_eval() {
+        // Apart from A-5-4-3-2, every hand keeps its place relative to every
+        // other hand, reversed
+        let wheel = |low: &DeuceSevenLow| low.ranks() == deuce_seven("Ac 5d 4h 3s 2c").ranks();
+        let deck = deck::generate().collect::<Vec<_>>();
+        let mut hands = utils::const_combos::<_, 5>(&deck[16..])
+            .map(|hand| {
+                (
+                    EVALUATOR.evaluate(hand).unwrap(),
+                    EVALUATOR.evaluate_deuce_to_seven(hand).unwrap(),
+                )
+            })
+            .filter(|(_, low)| !wheel(low))
+            .collect::<Vec<_>>();
+        hands.sort_unstable_by_key(|&(eval, _)| eval);
+        assert!(hands.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert!(hands
+            .windows(2)
+            .all(|pair| (pair[0].0 == pair[1].0) == (pair[0].1 == pair[1].1)));
+    }
+
+    #[test]
+    fn deuce_to_seven_best_five() {
+        assert_eq!(
+            deuce_seven("Ac 5c 4c 3c 2c 7d").to_string(),
+            "7-5-4-3-2 low"
+        );
+        assert_eq!(
+            deuce_seven("8c 8d 8h 3s 3c 2c 4d").to_string(),
+            "3-3-8-4-2 pair"
+        );
+        assert!(deuce_seven("7c 5d 4h 3s 2c").is_no_pair());
+        assert!(!deuce_seven("Ac 5c 4c 3c 2c").is_no_pair());
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        let cards = deck::generate().take(4).collect::<Vec<_>>();
+        assert_eq!(
+            EVALUATOR.evaluate_ace_to_five(&cards),
+            Err(EvalError::InvalidHandSize(4))
+        );
+        let doubled = [cards[0], cards[0], cards[1], cards[2], cards[3]];
+        assert_eq!(
+            EVALUATOR.evaluate_eight_or_better(doubled),
+            Err(EvalError::CardsNotUnique(CardSet::from([cards[0]])))
+        );
+        assert_eq!(
+            EVALUATOR.evaluate_deuce_to_seven(&cards),
+            Err(EvalError::InvalidHandSize(4))
+        );
+    }
+}
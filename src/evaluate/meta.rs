@@ -1,8 +1,19 @@
-use std::{cmp::Ordering, fmt, hash::Hash};
+use core::{cmp::Ordering, fmt, hash::Hash};
 
 use crate::{
     card::rank::Rank,
-    evaluate::{class::EvalClass, hand_rank::PokerHandRank},
+    evaluate::{
+        class::EvalClass,
+        hand_rank::{
+            constants::{
+                STRAIGHTS, WORST_FLUSH, WORST_FOUR_OF_A_KIND, WORST_FULL_HOUSE, WORST_HIGH_CARD,
+                WORST_PAIR, WORST_STRAIGHT, WORST_STRAIGHT_FLUSH, WORST_THREE_OF_A_KIND,
+                WORST_TWO_PAIR,
+            },
+            PokerHandRank,
+        },
+        utils,
+    },
 };
 
 /// Hand metadata stored in the lookup table. It contains slightly more
@@ -62,6 +73,83 @@ impl Meta {
         hand_rank: PokerHandRank::WORST,
     };
 
+    /// Rebuild the metadata for a hand rank, as it would be found in the lookup
+    /// table. Returns `None` if the hand rank is out of range.
+    pub fn from_hand_rank(hand_rank: PokerHandRank) -> Option<Self> {
+        let value = hand_rank.0;
+        let mut buf = [Rank::Two; 13];
+        let meta = match value {
+            _ if value < 1 => return None,
+            _ if value <= WORST_STRAIGHT_FLUSH => Self::StraightFlush {
+                hand_rank,
+                high_rank: utils::high_rank_from_rank_bits(STRAIGHTS[(value - 1) as usize]),
+            },
+            _ if value <= WORST_FOUR_OF_A_KIND => {
+                let offset = (value - WORST_STRAIGHT_FLUSH - 1) as usize;
+                Self::FourOfAKind {
+                    hand_rank,
+                    quads: Rank::ALL_VARIANTS[12 - offset / 12],
+                }
+            }
+            _ if value <= WORST_FULL_HOUSE => {
+                let offset = (value - WORST_FOUR_OF_A_KIND - 1) as usize;
+                let trips = Rank::ALL_VARIANTS[12 - offset / 12];
+                let len = utils::descending_ranks_except(&[trips], &mut buf);
+                Self::FullHouse {
+                    hand_rank,
+                    trips,
+                    pair: buf[..len][offset % 12],
+                }
+            }
+            _ if value <= WORST_FLUSH => {
+                let offset = (value - WORST_FULL_HOUSE - 1) as usize;
+                Self::Flush {
+                    hand_rank,
+                    high_rank: Self::nth_non_straight(offset)[0],
+                }
+            }
+            _ if value <= WORST_STRAIGHT => Self::Straight {
+                hand_rank,
+                high_rank: utils::high_rank_from_rank_bits(
+                    STRAIGHTS[(value - WORST_FLUSH - 1) as usize],
+                ),
+            },
+            _ if value <= WORST_THREE_OF_A_KIND => {
+                let offset = (value - WORST_STRAIGHT - 1) as usize;
+                Self::ThreeOfAKind {
+                    hand_rank,
+                    trips: Rank::ALL_VARIANTS[12 - offset / 66],
+                }
+            }
+            _ if value <= WORST_TWO_PAIR => {
+                let offset = (value - WORST_THREE_OF_A_KIND - 1) as usize;
+                let len = utils::descending_ranks_except(&[], &mut buf);
+                let [high_pair, low_pair] = utils::nth_combination(&buf[..len], offset / 11);
+                Self::TwoPair {
+                    hand_rank,
+                    high_pair,
+                    low_pair,
+                }
+            }
+            _ if value <= WORST_PAIR => {
+                let offset = (value - WORST_TWO_PAIR - 1) as usize;
+                Self::Pair {
+                    hand_rank,
+                    pair: Rank::ALL_VARIANTS[12 - offset / 220],
+                }
+            }
+            _ if value <= WORST_HIGH_CARD => {
+                let offset = (value - WORST_PAIR - 1) as usize;
+                Self::HighCard {
+                    hand_rank,
+                    high_rank: Self::nth_non_straight(offset)[0],
+                }
+            }
+            _ => return None,
+        };
+        Some(meta)
+    }
+
     pub(crate) const fn hand_rank(self) -> PokerHandRank {
         match self {
             // Is there a more elegant way to do this?
@@ -98,6 +186,98 @@ impl Meta {
         }
     }
 
+    /// Recover all five ranks of the hand, ordered by significance, from the
+    /// hand rank alone. Within each class, [`LookupTable::new`] hands out hand
+    /// ranks by walking kicker combinations from best to worst, so the offset
+    /// of a hand rank from the start of its class tells us which
+    /// combination of kickers the hand has.
+    ///
+    /// [`LookupTable::new`]: crate::evaluate::lookup_table::LookupTable::new
+    pub fn ranks(self) -> [Rank; 5] {
+        let hand_rank = self.hand_rank().0;
+        let mut buf = [Rank::Two; 13];
+        match self {
+            Self::StraightFlush { high_rank, .. } | Self::Straight { high_rank, .. } => {
+                if high_rank == Rank::Five {
+                    [Rank::Five, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]
+                } else {
+                    let high = high_rank as usize;
+                    [0, 1, 2, 3, 4].map(|i| Rank::ALL_VARIANTS[high - i])
+                }
+            }
+            Self::FourOfAKind { quads, .. } => {
+                let offset = (hand_rank - WORST_STRAIGHT_FLUSH - 1) as usize;
+                let len = utils::descending_ranks_except(&[quads], &mut buf);
+                let kicker = buf[..len][offset % len];
+                [quads, quads, quads, quads, kicker]
+            }
+            Self::FullHouse { trips, pair, .. } => [trips, trips, trips, pair, pair],
+            Self::ThreeOfAKind { trips, .. } => {
+                let offset = (hand_rank - WORST_STRAIGHT - 1) as usize;
+                let len = utils::descending_ranks_except(&[trips], &mut buf);
+                let combos = utils::binomial(len, 2);
+                let [k1, k2] = utils::nth_combination(&buf[..len], offset % combos);
+                [trips, trips, trips, k1, k2]
+            }
+            Self::TwoPair {
+                high_pair,
+                low_pair,
+                ..
+            } => {
+                let offset = (hand_rank - WORST_THREE_OF_A_KIND - 1) as usize;
+                let len = utils::descending_ranks_except(&[high_pair, low_pair], &mut buf);
+                let kicker = buf[..len][offset % len];
+                [high_pair, high_pair, low_pair, low_pair, kicker]
+            }
+            Self::Pair { pair, .. } => {
+                let offset = (hand_rank - WORST_TWO_PAIR - 1) as usize;
+                let len = utils::descending_ranks_except(&[pair], &mut buf);
+                let combos = utils::binomial(len, 3);
+                let [k1, k2, k3] = utils::nth_combination(&buf[..len], offset % combos);
+                [pair, pair, k1, k2, k3]
+            }
+            Self::Flush { .. } => {
+                let offset = (hand_rank - WORST_FULL_HOUSE - 1) as usize;
+                Self::nth_non_straight(offset)
+            }
+            Self::HighCard { .. } => {
+                let offset = (hand_rank - WORST_PAIR - 1) as usize;
+                Self::nth_non_straight(offset)
+            }
+        }
+    }
+
+    /// Find the ranks of the `index`th best five distinct ranks that do not
+    /// form a straight, which is the order flushes and high-card hands are
+    /// ranked in.
+    fn nth_non_straight(mut index: usize) -> [Rank; 5] {
+        let mut buf = [Rank::Two; 13];
+        let len = utils::descending_ranks_except(&[], &mut buf);
+        let all_ranks = &buf[..len];
+
+        // Find where each straight sits among all combinations, then skip past
+        // any that come before our target
+        let mut straight_indices = STRAIGHTS.map(|bits| {
+            let mut ranks = [Rank::Two; 5];
+            let mut i = 0;
+            for &rank in all_ranks {
+                if bits & (1 << rank as i16) != 0 {
+                    ranks[i] = rank;
+                    i += 1;
+                }
+            }
+            utils::combination_index(all_ranks, &ranks)
+        });
+        straight_indices.sort_unstable();
+        for straight_index in straight_indices {
+            if straight_index <= index {
+                index += 1;
+            }
+        }
+
+        utils::nth_combination(all_ranks, index)
+    }
+
     pub const fn is_high_card(self) -> bool { matches!(self, Self::HighCard { .. }) }
 
     pub const fn is_pair(self) -> bool { matches!(self, Self::Pair { .. }) }
@@ -134,17 +314,7 @@ impl PartialEq for Meta {
 impl Eq for Meta {}
 
 impl PartialOrd for Meta {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let hand_rank_self = self.hand_rank();
-        let hand_rank_other = other.hand_rank();
-        if hand_rank_self.is_better_than(hand_rank_other) {
-            Some(Ordering::Greater)
-        } else if hand_rank_self == hand_rank_other {
-            Some(Ordering::Equal)
-        } else {
-            Some(Ordering::Less)
-        }
-    }
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
 }
 
 impl Ord for Meta {
@@ -162,7 +332,7 @@ impl Ord for Meta {
 }
 
 impl Hash for Meta {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.hand_rank().hash(state); }
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) { self.hand_rank().hash(state); }
 }
 
 impl fmt::Display for Meta {
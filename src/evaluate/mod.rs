@@ -21,30 +21,74 @@
 //! to be conservative here. All [`Evaluator`] methods borrow `Self` immutably,
 //! so pass it around as you see fit.
 //!
+//! Tables that only some kinds of evaluation need, like the one for
+//! ace-to-five lowball or the layout used by [`Evaluator::evaluate_many`], are
+//! built the first time they are used rather than by [`Evaluator::new`], so
+//! that first use takes a little longer.
+//!
+//! The [`Evaluator`] and the other evaluators need the `std` feature. Without
+//! it, hands can still be evaluated with the static lookup table of the
+//! `static_lookup` feature.
+//!
 //! [`Card`]: crate::Card
 //! [the `card` module`]: crate::card
 
+#[cfg(any(feature = "std", feature = "static_lookup"))]
 #[macro_use]
 mod evaluation;
 
 mod class;
 mod eval;
 mod hand_rank;
-// This needs to be public to bootstrap a lookup table in a build script, rather
-// than shipping the `table.in` file, which is large and unnecessary
+#[cfg(feature = "std")]
+mod hi_lo;
+// The build script compiles this module on its own, rather than through the
+// library, to generate the static lookup table. It stays public, as it was
+// when build scripts had to bootstrap a table through the library.
+#[cfg(feature = "std")]
 #[doc(hidden)]
 pub mod lookup_table;
+mod low;
 mod meta;
+#[cfg(feature = "std")]
+mod seven;
+#[cfg(feature = "std")]
+mod short_deck;
 #[cfg(feature = "static_lookup")]
 pub mod static_lookup;
-mod utils;
+pub(crate) mod utils;
+#[cfg(feature = "std")]
+mod wild;
 
 #[doc(inline)]
 pub use class::EvalClass;
 #[doc(inline)]
 pub use eval::Eval;
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use hi_lo::HiLo;
+#[doc(inline)]
+pub use low::{AceFiveLow, DeuceSevenLow};
+#[cfg(feature = "std")]
+use once_cell::sync::OnceCell;
+#[cfg(feature = "std")]
+use rustc_hash::FxHashMap;
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use seven::SevenCardEvaluator;
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use short_deck::{ShortDeckEval, ShortDeckEvaluator, ShortDeckRules};
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use wild::{WildCard, WildEval, WildEvalClass};
 
-use crate::{card::Card, error::EvalError, evaluate::lookup_table::LookupTable};
+#[cfg(feature = "std")]
+use crate::{
+    card::{Card, CardSet, Rank},
+    error::EvalError,
+    evaluate::{lookup_table::LookupTable, meta::Meta},
+};
 
 /// This structure does all the heavy lifting of evaluating poker hands.
 ///
@@ -73,13 +117,41 @@ use crate::{card::Card, error::EvalError, evaluate::lookup_table::LookupTable};
 ///     "Full house, jacks over fours"
 /// );
 /// ```
-#[derive(Clone, Debug, PartialEq, Eq, Default)]
-pub struct Evaluator(LookupTable);
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Default)]
+pub struct Evaluator {
+    table: LookupTable,
+    // The same table, laid out for `evaluate_many`, and built on first use
+    bulk_lookup: OnceCell<BulkLookup>,
+    // Only needed for lowball and hi-lo games, so built on first use
+    ace_five_lookup: OnceCell<FxHashMap<i32, AceFiveLow>>,
+}
 
+#[cfg(feature = "std")]
 impl Evaluator {
     /// Create a new [`Evaluator`]. Try to call this method only once and share
     /// the instance as much as possible.
-    pub fn new() -> Self { Self(LookupTable::new()) }
+    pub fn new() -> Self {
+        Self {
+            table: LookupTable::new(),
+            bulk_lookup: OnceCell::new(),
+            ace_five_lookup: OnceCell::new(),
+        }
+    }
+
+    /// The bulk evaluation layout of the lookup table, building it if this is
+    /// its first use.
+    fn bulk_lookup(&self) -> &BulkLookup {
+        self.bulk_lookup.get_or_init(|| BulkLookup {
+            dense: self.table.dense_lookup().into_boxed_slice(),
+            meta: self.table.meta_lookup().into_boxed_slice(),
+        })
+    }
+
+    /// The ace-to-five lowball table, building it if this is its first use.
+    fn ace_five_lookup(&self) -> &FxHashMap<i32, AceFiveLow> {
+        self.ace_five_lookup.get_or_init(low::ace_five_lookup)
+    }
 
     /// Evaluate a hand. This function takes anything that implements
     /// `AsRef<[Card]>`, so owned or borrowed slices of `Vec`s work fine
@@ -149,8 +221,486 @@ impl Evaluator {
         let cards = cards.as_ref();
         evaluation::evaluate(self, cards)
     }
+
+    /// Evaluate a hand stored in a [`CardSet`]. A [`CardSet`] can't hold the
+    /// same card twice, so unlike [`Evaluator::evaluate`], this method skips
+    /// checking the cards for uniqueness.
+    ///
+    /// # Errors
+    ///
+    /// This function will fail if the set has less than five cards. See
+    /// [`EvalError`] for more.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{cards, CardSet, Evaluator};
+    ///
+    /// let eval = Evaluator::new();
+    /// let mut hand: CardSet = cards!("Th Jh Qh Kh")
+    ///     .try_collect()
+    ///     .expect("couldn't parse cards");
+    /// assert!(eval.evaluate_set(hand).is_err());
+    ///
+    /// hand.insert("Ah".parse().expect("couldn't parse card"));
+    /// let result = eval.evaluate_set(hand).expect("couldn't evaluate hand");
+    /// assert!(result.is_royal_flush());
+    /// ```
+    pub fn evaluate_set(&self, cards: CardSet) -> Result<Eval, EvalError> {
+        evaluation::evaluate_set(self, cards)
+    }
+
+    /// Evaluate many hands of the same size at once, writing the result for
+    /// `hands[i]` into `out[i]`. Every hand is checked for uniqueness up front,
+    /// before any of them are evaluated, so if this fails, `out` is left
+    /// unchanged.
+    ///
+    /// # Errors
+    ///
+    /// This function will fail if the hands have less than five cards, or if
+    /// not all the cards of some hand are unique. See [`EvalError`] for more.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `out` and `hands` have different lengths.
+    ///
+    /// # Performance
+    ///
+    /// Evaluating hands in bulk is considerably faster than calling
+    /// [`Evaluator::evaluate`] for each one. Hands are evaluated several at a
+    /// time, with their cards laid out so that the compiler can vectorize the
+    /// work that doesn't involve looking up hands in the tables. If the hands
+    /// are known to be valid already, [`Evaluator::evaluate_many_unique`]
+    /// skips the checks altogether.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{cards, Eval, Evaluator};
+    ///
+    /// let eval = Evaluator::new();
+    /// let hands = [
+    ///     cards!(Ace of Spades, King of Spades, Queen of Spades, Jack of Spades, Ten of Spades),
+    ///     cards!(Two of Clubs, Two of Hearts, Five of Spades, Seven of Diamonds, Nine of Clubs),
+    /// ];
+    /// let mut results = [Eval::WORST; 2];
+    /// eval.evaluate_many(&hands, &mut results)
+    ///     .expect("couldn't evaluate hands");
+    /// assert!(results[0].is_royal_flush());
+    /// assert!(results[1].is_pair());
+    /// ```
+    pub fn evaluate_many<const N: usize>(
+        &self,
+        hands: &[[Card; N]],
+        out: &mut [Eval],
+    ) -> Result<(), EvalError> {
+        evaluation::evaluate_many(self, hands, out)
+    }
+
+    /// Like [`Evaluator::evaluate_many`], but skip checking the hands for
+    /// uniqueness, for hands that are already known to hold unique cards, such
+    /// as hands dealt from a [`Deck`](crate::deck::Deck).
+    ///
+    /// # Errors
+    ///
+    /// This function will fail if the hands have less than five cards. See
+    /// [`EvalError`] for more.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `out` and `hands` have different lengths.
+    /// A hand with duplicate cards has no meaningful result, and its result may
+    /// be any [`Eval`].
+    pub fn evaluate_many_unique<const N: usize>(
+        &self,
+        hands: &[[Card; N]],
+        out: &mut [Eval],
+    ) -> Result<(), EvalError> {
+        evaluation::evaluate_many_unique(self, hands, out)
+    }
+
+    /// Like [`Evaluator::evaluate_many`], but split the hands between the
+    /// threads of the current [rayon] thread pool. Every result only depends
+    /// on its own hand, so the results are the same no matter how many threads
+    /// there are, and the error for invalid hands is always the one for the
+    /// first invalid hand. This method needs the `rayon` feature.
+    ///
+    /// # Errors
+    ///
+    /// This function will fail if the hands have less than five cards, or if
+    /// not all the cards of some hand are unique. See [`EvalError`] for more.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `out` and `hands` have different lengths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{deck, Card, Eval, Evaluator};
+    ///
+    /// let eval = Evaluator::new();
+    /// let deck: Vec<Card> = deck::generate().collect();
+    /// let hands: Vec<[Card; 5]> = deck
+    ///     .chunks_exact(5)
+    ///     .map(|hand| [hand[0], hand[1], hand[2], hand[3], hand[4]])
+    ///     .collect();
+    /// let mut results = vec![Eval::WORST; hands.len()];
+    /// eval.par_evaluate_many(&hands, &mut results)
+    ///     .expect("couldn't evaluate hands");
+    /// assert_eq!(Ok(results[0]), eval.evaluate(hands[0]));
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_evaluate_many<const N: usize>(
+        &self,
+        hands: &[[Card; N]],
+        out: &mut [Eval],
+    ) -> Result<(), EvalError> {
+        evaluation::par_evaluate_many(self, hands, out)
+    }
+
+    /// Like [`Evaluator::par_evaluate_many`], but skip checking the hands for
+    /// uniqueness, as in [`Evaluator::evaluate_many_unique`]. This method
+    /// needs the `rayon` feature.
+    ///
+    /// # Errors
+    ///
+    /// This function will fail if the hands have less than five cards. See
+    /// [`EvalError`] for more.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `out` and `hands` have different lengths.
+    /// A hand with duplicate cards has no meaningful result, and its result may
+    /// be any [`Eval`].
+    #[cfg(feature = "rayon")]
+    pub fn par_evaluate_many_unique<const N: usize>(
+        &self,
+        hands: &[[Card; N]],
+        out: &mut [Eval],
+    ) -> Result<(), EvalError> {
+        evaluation::par_evaluate_many_unique(self, hands, out)
+    }
+
+    /// Evaluate cards that are already known to be unique, such as cards dealt
+    /// from a [`CardSet`], skipping the uniqueness check.
+    pub(crate) fn evaluate_unique(&self, cards: &[Card]) -> Result<Eval, EvalError> {
+        evaluation::evaluate_unique(self, cards)
+    }
+
+    /// Evaluate a hand like [`Evaluator::evaluate`], but also return the five
+    /// cards that make up the best hand. This is useful when you need to know
+    /// which cards "play", such as for highlighting them at showdown.
+    ///
+    /// The cards are ordered by significance: the cards of the largest group
+    /// of matching ranks come first (quads, trips, then pairs), followed by
+    /// kickers from highest to lowest. For example, a full house lists its
+    /// trips before its pair, and a five-high straight ends with its ace.
+    ///
+    /// # Errors
+    ///
+    /// This function will fail if the total number of cards is less than five,
+    /// or if not all the cards passed in are unique. See
+    /// [`EvalError`] for more.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{box_cards, cards, Card, EvalClass, Evaluator, Rank};
+    ///
+    /// let eval = Evaluator::new();
+    /// let board: Vec<Card> = cards!("7d 2c Ks 7h 3s")
+    ///     .try_collect()
+    ///     .expect("couldn't parse cards");
+    /// let hand: Vec<Card> = cards!("Kh 7c").try_collect().expect("couldn't parse cards");
+    ///
+    /// let (result, best) = eval
+    ///     .evaluate_best_hand(box_cards!(board, hand))
+    ///     .expect("couldn't evaluate hand");
+    /// assert!(matches!(
+    ///     result.class(),
+    ///     EvalClass::FullHouse {
+    ///         trips: Rank::Seven,
+    ///         pair: Rank::King
+    ///     }
+    /// ));
+    /// let expected: Vec<Card> = cards!("7d 7h 7c Ks Kh")
+    ///     .try_collect()
+    ///     .expect("couldn't parse cards");
+    /// assert_eq!(best, *expected);
+    /// ```
+    pub fn evaluate_best_hand<C: AsRef<[Card]>>(
+        &self,
+        cards: C,
+    ) -> Result<(Eval, [Card; 5]), EvalError> {
+        let cards = cards.as_ref();
+        evaluation::evaluate_best_hand(self, cards)
+    }
+
+    /// Evaluate an Omaha hand. Unlike [`Evaluator::evaluate`], which finds the
+    /// best five cards out of all cards given, this finds the best hand that
+    /// uses exactly two of the `hole_cards` and exactly three cards from the
+    /// `board`, as the rules of Omaha require. Four, five, and six hole cards
+    /// are supported, for PLO4, PLO5, and PLO6.
+    ///
+    /// # Errors
+    ///
+    /// This function will fail if there are fewer than 4 or more than 6 hole
+    /// cards, if the board has fewer than 3 or more than 5 cards, or if not
+    /// all the cards passed in are unique. See [`EvalError`] for more.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{cards, Card, Evaluator};
+    ///
+    /// let eval = Evaluator::new();
+    /// let hole_cards: Vec<Card> = cards!("Ah Kc Qd Js")
+    ///     .try_collect()
+    ///     .expect("couldn't parse cards");
+    /// let board: Vec<Card> = cards!("2h 5h 8h 9h Tc")
+    ///     .try_collect()
+    ///     .expect("couldn't parse cards");
+    ///
+    /// // In Hold'em, the ace of hearts would make a flush...
+    /// let all_cards = [&hole_cards[..], &board[..]].concat();
+    /// assert!(eval
+    ///     .evaluate(&all_cards)
+    ///     .expect("couldn't evaluate hand")
+    ///     .is_flush());
+    ///
+    /// // ...but in Omaha, two hearts are needed from the hand
+    /// let result = eval
+    ///     .evaluate_omaha(&hole_cards, &board)
+    ///     .expect("couldn't evaluate hand");
+    /// assert!(result.is_straight());
+    /// ```
+    pub fn evaluate_omaha<H, B>(&self, hole_cards: H, board: B) -> Result<Eval, EvalError>
+    where
+        H: AsRef<[Card]>,
+        B: AsRef<[Card]>,
+    {
+        evaluation::evaluate_omaha(self, hole_cards.as_ref(), board.as_ref())
+    }
+
+    /// Evaluate a hand for ace-to-five low, as in Razz. Aces play low, and
+    /// straights and flushes are ignored. Given more than five cards, the best
+    /// low among any five of them is returned.
+    ///
+    /// # Errors
+    ///
+    /// This function will fail if the total number of cards is less than five,
+    /// or if not all the cards passed in are unique. See [`EvalError`] for
+    /// more.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{cards, Card, Evaluator};
+    ///
+    /// let eval = Evaluator::new();
+    /// let razz_hand: Vec<Card> = cards!("Kc 7d 7h 4s 2c 2d Ac")
+    ///     .try_collect()
+    ///     .expect("couldn't parse cards");
+    /// let result = eval
+    ///     .evaluate_ace_to_five(razz_hand)
+    ///     .expect("couldn't evaluate hand");
+    /// assert_eq!(result.to_string(), "K-7-4-2-A low");
+    /// ```
+    pub fn evaluate_ace_to_five<C: AsRef<[Card]>>(
+        &self,
+        cards: C,
+    ) -> Result<AceFiveLow, EvalError> {
+        low::evaluate_ace_to_five(self.ace_five_lookup(), cards.as_ref())
+    }
+
+    /// Evaluate a hand for ace-to-five low like
+    /// [`Evaluator::evaluate_ace_to_five`], but only return the low if it
+    /// qualifies as "eight or better": five cards of different ranks, none
+    /// higher than an eight. Otherwise, `Ok(None)` is returned, as there is no
+    /// qualifying low.
+    ///
+    /// # Errors
+    ///
+    /// This function will fail if the total number of cards is less than five,
+    /// or if not all the cards passed in are unique. See [`EvalError`] for
+    /// more.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{cards, Card, Evaluator};
+    ///
+    /// let eval = Evaluator::new();
+    /// let hand: Vec<Card> = cards!("9c 7d 7h 4s 2c 2d Ac")
+    ///     .try_collect()
+    ///     .expect("couldn't parse cards");
+    /// assert_eq!(eval.evaluate_eight_or_better(hand), Ok(None));
+    /// ```
+    pub fn evaluate_eight_or_better<C: AsRef<[Card]>>(
+        &self,
+        cards: C,
+    ) -> Result<Option<AceFiveLow>, EvalError> {
+        self.evaluate_ace_to_five(cards)
+            .map(|low| Some(low).filter(|low| low.is_eight_or_better()))
+    }
+
+    /// Evaluate a hand for deuce-to-seven low, as in 2-7 Triple Draw. Aces
+    /// play high, and straights and flushes count against the hand. Given more
+    /// than five cards, the best low among any five of them is returned.
+    ///
+    /// # Errors
+    ///
+    /// This function will fail if the total number of cards is less than five,
+    /// or if not all the cards passed in are unique. See [`EvalError`] for
+    /// more.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{cards, Card, Evaluator};
+    ///
+    /// let eval = Evaluator::new();
+    /// let hand: Vec<Card> = cards!("8h 6h 5h 4h 2h 9c")
+    ///     .try_collect()
+    ///     .expect("couldn't parse cards");
+    /// let result = eval
+    ///     .evaluate_deuce_to_seven(hand)
+    ///     .expect("couldn't evaluate hand");
+    /// // Breaking the flush with the nine is better than keeping the eight
+    /// assert_eq!(result.to_string(), "9-6-5-4-2 low");
+    /// ```
+    pub fn evaluate_deuce_to_seven<C: AsRef<[Card]>>(
+        &self,
+        cards: C,
+    ) -> Result<DeuceSevenLow, EvalError> {
+        evaluation::evaluate_deuce_to_seven(self, cards.as_ref())
+    }
+
+    /// Evaluate an Omaha Hi-Lo (Omaha-8) hand, finding both the best high hand
+    /// and the best eight-or-better low. Each half must use exactly two of the
+    /// `hole_cards` and exactly three cards from the `board`, but the two
+    /// halves may use different cards.
+    ///
+    /// # Errors
+    ///
+    /// This function will fail for the same reasons as
+    /// [`Evaluator::evaluate_omaha`]. See [`EvalError`] for more.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{cards, Card, Evaluator};
+    ///
+    /// let eval = Evaluator::new();
+    /// let hole_cards: Vec<Card> = cards!("Kc Kd Ah 2h")
+    ///     .try_collect()
+    ///     .expect("couldn't parse cards");
+    /// let board: Vec<Card> = cards!("Ks 3c 4d 8s Qh")
+    ///     .try_collect()
+    ///     .expect("couldn't parse cards");
+    ///
+    /// let result = eval
+    ///     .evaluate_omaha_hi_lo(hole_cards, board)
+    ///     .expect("couldn't evaluate hand");
+    /// assert!(result.high().is_three_of_a_kind());
+    /// assert_eq!(
+    ///     result.low().map(|low| low.to_string()),
+    ///     Some("8-4-3-2-A low".into())
+    /// );
+    /// ```
+    pub fn evaluate_omaha_hi_lo<H, B>(&self, hole_cards: H, board: B) -> Result<HiLo, EvalError>
+    where
+        H: AsRef<[Card]>,
+        B: AsRef<[Card]>,
+    {
+        let (hole_cards, board) = (hole_cards.as_ref(), board.as_ref());
+        let high = self.evaluate_omaha(hole_cards, board)?;
+        let low = low::omaha_eight_or_better(self.ace_five_lookup(), hole_cards, board);
+        Ok(HiLo::new(high, low))
+    }
+
+    /// Evaluate a Seven Card Stud Hi-Lo (Stud-8) hand, finding both the best
+    /// high hand and the best eight-or-better low among any five of the
+    /// player's cards.
+    ///
+    /// # Errors
+    ///
+    /// This function will fail if the total number of cards is less than five,
+    /// or if not all the cards passed in are unique. See [`EvalError`] for
+    /// more.
+    pub fn evaluate_stud_hi_lo<C: AsRef<[Card]>>(&self, cards: C) -> Result<HiLo, EvalError> {
+        let cards = cards.as_ref();
+        let high = self.evaluate(cards)?;
+        let low = low::best_ace_to_five(self.ace_five_lookup(), cards);
+        Ok(HiLo::new(high, Some(low)))
+    }
+
+    /// Evaluate a hand with wild cards, as in Joker Poker or Deuces Wild.
+    /// Jokers are always wild, and so are natural cards of any of the
+    /// `wild_ranks`. Each wild card is substituted with whichever card makes
+    /// the best hand, which may be five of a kind. Given more than five cards,
+    /// the best hand among any five of them is returned.
+    ///
+    /// A wild card may stand in for a card that is already in the hand, so
+    /// `Ac Ad Ah As` and a joker make five aces.
+    ///
+    /// # Errors
+    ///
+    /// This function will fail if the total number of cards is less than five,
+    /// or if not all the natural cards passed in are unique. Any number of
+    /// jokers is allowed. See [`EvalError`] for more.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{cards, Card, EvalClass, Evaluator, Rank, WildCard, WildEvalClass};
+    ///
+    /// let eval = Evaluator::new();
+    /// let mut hand: Vec<WildCard> = cards!("Kc Kd 2h 7s")
+    ///     .map(|card| card.map(WildCard::from))
+    ///     .collect::<Result<_, _>>()
+    ///     .expect("couldn't parse cards");
+    /// hand.push(WildCard::Joker);
+    ///
+    /// // Deuces and the joker are wild
+    /// let result = eval
+    ///     .evaluate_wild(&hand, &[Rank::Two])
+    ///     .expect("couldn't evaluate hand");
+    /// assert_eq!(
+    ///     result.class(),
+    ///     WildEvalClass::Natural(EvalClass::FourOfAKind { quads: Rank::King })
+    /// );
+    /// assert_eq!(result.to_string(), "Four of a kind, kings");
+    /// ```
+    pub fn evaluate_wild<C: AsRef<[WildCard]>>(
+        &self,
+        cards: C,
+        wild_ranks: &[Rank],
+    ) -> Result<WildEval, EvalError> {
+        wild::evaluate_wild(self, cards.as_ref(), wild_ranks)
+    }
+}
+
+/// The lookup table of an [`Evaluator`], laid out for `evaluate_many` as in
+/// [`LookupTable::dense_lookup`] and [`LookupTable::meta_lookup`].
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+struct BulkLookup {
+    dense: Box<[i16]>,
+    meta: Box<[Meta]>,
 }
 
+// The tables built on first use are derived from `table`, so whether they have
+// been built yet doesn't make two evaluators different.
+#[cfg(feature = "std")]
+impl PartialEq for Evaluator {
+    fn eq(&self, other: &Self) -> bool { self.table == other.table }
+}
+
+#[cfg(feature = "std")]
+impl Eq for Evaluator {}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use std::collections::HashSet;
@@ -159,6 +709,7 @@ pub(crate) mod tests {
 
     use super::*;
     use crate::{
+        card,
         card::Card,
         deck,
         evaluate::{hand_rank::PokerHandRank, utils},
@@ -173,7 +724,7 @@ pub(crate) mod tests {
         let deck = deck::generate().collect::<Vec<_>>();
         let gen = utils::const_combos::<_, 5>(&deck);
         let evals = gen.fold(HashSet::with_capacity(7462), |mut ints, hand| {
-            ints.insert(EVALUATOR.evaluate(&hand).unwrap());
+            ints.insert(EVALUATOR.evaluate(hand).unwrap());
             ints
         });
         assert_eq!(evals.len(), 7462);
@@ -199,6 +750,206 @@ pub(crate) mod tests {
         representative_hand_evaluates_correctly::<SevenCardHand>(7);
     }
 
+    #[test]
+    fn tables_built_on_first_use() {
+        let used = Evaluator::new();
+        let fresh = used.clone();
+        let hands = [[
+            card!(Ace, Clubs),
+            card!(Two, Diamonds),
+            card!(Three, Hearts),
+            card!(Four, Spades),
+            card!(Six, Clubs),
+        ]];
+        let mut out = [Eval::WORST];
+        used.evaluate_many(&hands, &mut out).unwrap();
+        assert_eq!(out[0], fresh.evaluate(hands[0]).unwrap());
+        used.evaluate_ace_to_five(hands[0]).unwrap();
+        assert_eq!(used, fresh);
+        assert_eq!(used.clone(), fresh);
+    }
+
+    #[test]
+    fn best_hand_matches_evaluate() {
+        for &hand in SevenCardHand::ALL_HANDS {
+            let cards = Card::parse_to_iter(hand).try_collect::<Box<_>>().unwrap();
+            let (result, best) = EVALUATOR.evaluate_best_hand(&cards).unwrap();
+            assert_eq!(result, EVALUATOR.evaluate(&cards).unwrap());
+            assert_eq!(result, EVALUATOR.evaluate(best).unwrap());
+            assert!(best.iter().all(|card| cards.contains(card)));
+        }
+    }
+
+    #[test]
+    fn evaluate_set_matches_evaluate() {
+        for &hand in SevenCardHand::ALL_HANDS {
+            let cards = Card::parse_to_iter(hand).try_collect::<Box<_>>().unwrap();
+            let set = CardSet::from(&*cards);
+            assert_eq!(
+                EVALUATOR.evaluate_set(set).unwrap(),
+                EVALUATOR.evaluate(&cards).unwrap()
+            );
+        }
+        assert_eq!(
+            EVALUATOR.evaluate_set(CardSet::new()),
+            Err(EvalError::InvalidHandSize(0))
+        );
+    }
+
+    #[test]
+    fn evaluate_many_matches_evaluate() {
+        // Every five-card hand, so every lane of every group gets all kinds of
+        // hands, and the last group is only partly filled
+        let deck = deck::generate().collect::<Vec<_>>();
+        let hands = utils::const_combos::<_, 5>(&deck).collect::<Vec<_>>();
+        let mut results = vec![Eval::WORST; hands.len()];
+        EVALUATOR.evaluate_many(&hands, &mut results).unwrap();
+        for (hand, result) in hands.iter().zip(&results) {
+            assert_eq!(Ok(*result), EVALUATOR.evaluate(hand));
+        }
+
+        let hands = SevenCardHand::ALL_HANDS
+            .iter()
+            .map(|&hand| {
+                let cards = Card::parse_to_iter(hand).try_collect::<Vec<_>>().unwrap();
+                <[Card; 7]>::try_from(cards).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let mut results = vec![Eval::WORST; hands.len()];
+        EVALUATOR
+            .evaluate_many_unique(&hands, &mut results)
+            .unwrap();
+        for (hand, result) in hands.iter().zip(&results) {
+            assert_eq!(Ok(*result), EVALUATOR.evaluate(hand));
+        }
+    }
+
+    #[test]
+    fn evaluate_many_invalid_inputs() {
+        let cards = deck::generate().collect::<Vec<_>>();
+        let mut results = [Eval::WORST; 2];
+        assert_eq!(
+            EVALUATOR.evaluate_many(&[[cards[0]; 4]; 2], &mut results),
+            Err(EvalError::InvalidHandSize(4))
+        );
+        let mut hands = [[cards[0], cards[1], cards[2], cards[3], cards[4]]; 2];
+        hands[1][4] = cards[0];
+        assert_eq!(
+            EVALUATOR.evaluate_many(&hands, &mut results),
+            Err(EvalError::CardsNotUnique(CardSet::from([cards[0]])))
+        );
+        assert_eq!(results, [Eval::WORST; 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn evaluate_many_mismatched_output() {
+        let hand = deck::generate().take(5).collect::<Vec<_>>();
+        let hands = [<[Card; 5]>::try_from(hand).unwrap(); 3];
+        let _ = EVALUATOR.evaluate_many(&hands, &mut [Eval::WORST; 2]);
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn par_evaluate_many_is_deterministic() {
+        let deck = deck::generate().collect::<Vec<_>>();
+        let hands = utils::const_combos::<_, 5>(&deck).collect::<Vec<_>>();
+        let mut expected = vec![Eval::WORST; hands.len()];
+        EVALUATOR.evaluate_many(&hands, &mut expected).unwrap();
+        for threads in [1, 3] {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            let mut results = vec![Eval::WORST; hands.len()];
+            pool.install(|| EVALUATOR.par_evaluate_many(&hands, &mut results))
+                .unwrap();
+            assert_eq!(results, expected);
+        }
+
+        // The first invalid hand is reported, wherever it lands
+        let mut hands = hands;
+        let mut results = vec![Eval::WORST; hands.len()];
+        for &i in &[hands.len() - 1, 100_000, 1] {
+            hands[i][4] = hands[i][0];
+        }
+        assert_eq!(
+            EVALUATOR.par_evaluate_many(&hands, &mut results),
+            Err(EvalError::CardsNotUnique(CardSet::from([hands[1][0]])))
+        );
+    }
+
+    #[test]
+    fn omaha_uses_two_hole_cards_and_three_board_cards() {
+        let check = |hole_cards: &str, board: &str| {
+            let hole_cards = Card::parse_to_iter(hole_cards.split_whitespace())
+                .try_collect::<Vec<_>>()
+                .unwrap();
+            let board = Card::parse_to_iter(board.split_whitespace())
+                .try_collect::<Vec<_>>()
+                .unwrap();
+            EVALUATOR.evaluate_omaha(hole_cards, board).unwrap()
+        };
+
+        // A single hole card can't complete a flush or a straight
+        assert!(check("Ah Kc Qd Js", "2h 5h 8h 9h Tc").is_straight());
+        assert!(check("9c 2d 2s Kh", "5c 6d 7h 8s Ad").is_pair());
+        // The board can't play on its own
+        assert!(check("2c 3d 4s 5c", "Ah Kh Qh Jh Th").is_high_card());
+        // Quads on board leave room for only one of them
+        assert!(check("Kc Kd 2c 3d", "As Ad Ac Ah 7s").is_full_house());
+        // Five and six hole cards
+        assert!(check("Ah Kc Qd Js 7h", "2h 5h 8h 9h Tc").is_flush());
+        assert!(check("Ah Kc Qd Js 3c 4d", "2h 5h 8h 9h Tc").is_straight());
+    }
+
+    #[test]
+    fn omaha_invalid_inputs() {
+        let cards = deck::generate().collect::<Vec<_>>();
+        assert_eq!(
+            EVALUATOR.evaluate_omaha(&cards[..3], &cards[10..15]),
+            Err(EvalError::InvalidHoleCardCount(3))
+        );
+        assert_eq!(
+            EVALUATOR.evaluate_omaha(&cards[..7], &cards[10..15]),
+            Err(EvalError::InvalidHoleCardCount(7))
+        );
+        assert_eq!(
+            EVALUATOR.evaluate_omaha(&cards[..4], &cards[10..12]),
+            Err(EvalError::InvalidBoardSize(2))
+        );
+        assert_eq!(
+            EVALUATOR.evaluate_omaha(&cards[..4], &cards[10..16]),
+            Err(EvalError::InvalidBoardSize(6))
+        );
+        assert!(matches!(
+            EVALUATOR.evaluate_omaha(&cards[..4], &cards[3..6]),
+            Err(EvalError::CardsNotUnique(_))
+        ));
+    }
+
+    #[test]
+    fn best_hand_ordered_by_significance() {
+        let check = |cards: &str, expected: &str| {
+            let cards = Card::parse_to_iter(cards.split_whitespace())
+                .try_collect::<Box<_>>()
+                .unwrap();
+            let expected = Card::parse_to_iter(expected.split_whitespace())
+                .try_collect::<Vec<_>>()
+                .unwrap();
+            let (_, best) = EVALUATOR.evaluate_best_hand(cards).unwrap();
+            assert_eq!(best, *expected);
+        };
+
+        check("3c 9d Ah 9s 2d Kc 9h", "9d 9s 9h Ah Kc");
+        check("Qh 5c 3d 5d Qs Jc 2h", "Qh Qs 5c 5d Jc");
+        check("2s 8c Kd 8h Ah 4c", "8c 8h Ah Kd 4c");
+        check("2c 4d 3h Ah 5s Kd Qc", "5s 4d 3h 2c Ah");
+        check("2c 4c 3c Ac 5c", "5c 4c 3c 2c Ac");
+        check("Td Ts 4c 4h Th 4s", "Td Ts Th 4c 4h");
+        check("Jc 8c 2c 3c Qc 9h", "Qc Jc 8c 3c 2c");
+    }
+
     fn representative_hand_evaluates_correctly<T: RepresentativeHand>(hand_size: usize) {
         assert!(T::ALL_HANDS.iter().all(|&hand| hand.len() == hand_size));
 
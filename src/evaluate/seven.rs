@@ -0,0 +1,289 @@
+use crate::{
+    constants::PRIMES,
+    evaluate::{lookup_table::LookupTable, meta::Meta, utils},
+    Card, Eval, EvalError,
+};
+
+/// The number of multisets of seven ranks, including the impossible ones that
+/// have five or more cards of one rank, which are left as [`Meta::WORST`].
+const MULTISETS: usize = utils::binomial(13 + 7 - 1, 7);
+
+/// `OFFSETS[rank][lower][count]` is how much `count` cards of the rank `rank`
+/// add to the index of a hand's multiset of ranks, when `lower` cards of the
+/// hand have lower ranks. Summing these over every rank numbers each multiset
+/// from zero up to [`MULTISETS`], following the combinatorial number system:
+/// the `i`th lowest card of the hand, of rank `r`, adds "`r + i` choose
+/// `i + 1`".
+const OFFSETS: [[[u16; 8]; 8]; 13] = {
+    let mut offsets = [[[0; 8]; 8]; 13];
+    let mut rank = 0;
+    while rank < 13 {
+        let mut lower = 0;
+        while lower < 8 {
+            let mut count = 1;
+            while lower + count <= 7 {
+                let i = lower + count - 1;
+                offsets[rank][lower][count] =
+                    offsets[rank][lower][count - 1] + utils::binomial(rank + i, i + 1) as u16;
+                count += 1;
+            }
+            lower += 1;
+        }
+        rank += 1;
+    }
+    offsets
+};
+
+/// An evaluator for seven-card hands, as in Texas Holdem or Seven Card Stud,
+/// that finds the best five-card hand with a single table lookup, rather than
+/// trying each of the 21 ways to choose five of the seven cards.
+///
+/// A seven-card hand with five or more cards of one suit can't also make a
+/// full house or four of a kind, so it is looked up by the ranks of that suit
+/// alone. Any other hand is looked up by its multiset of ranks. Both tables
+/// are built from the same lookup tables as the [`Evaluator`], so results are
+/// always identical to [`Evaluator::evaluate`].
+///
+/// Building the tables takes a few milliseconds, considerably longer than
+/// [`Evaluator::new`], so it pays off when evaluating many hands, as in equity
+/// calculations. Create one `SevenCardEvaluator` early and reuse it.
+///
+/// # Example
+///
+/// ```
+/// use poker::{cards, Card, Evaluator, SevenCardEvaluator};
+///
+/// let eval = Evaluator::new();
+/// let seven_card_eval = SevenCardEvaluator::new();
+/// let hand: Vec<Card> = cards!("Ks Kd 7h 7c 2d 3s 9h")
+///     .try_collect()
+///     .expect("couldn't parse cards");
+///
+/// let result = seven_card_eval
+///     .evaluate(&hand)
+///     .expect("couldn't evaluate hand");
+/// assert!(result.is_two_pair());
+/// assert_eq!(Ok(result), eval.evaluate(&hand));
+/// ```
+///
+/// [`Evaluator`]: crate::Evaluator
+/// [`Evaluator::evaluate`]: crate::Evaluator::evaluate
+/// [`Evaluator::new`]: crate::Evaluator::new
+#[derive(Debug, Clone)]
+pub struct SevenCardEvaluator {
+    // Indexed by the rank bits of the flush suit
+    flush_lookup: Box<[Meta]>,
+    // Indexed by the multiset of ranks, see `multiset_index`
+    unsuited_lookup: Box<[Meta]>,
+}
+
+impl SevenCardEvaluator {
+    /// Create a new [`SevenCardEvaluator`], building its lookup tables.
+    pub fn new() -> Self {
+        let table = LookupTable::new();
+        let best = |metas: &mut dyn Iterator<Item = Meta>| {
+            metas
+                .map(Eval)
+                .max()
+                .expect("there is always at least one combination")
+                .0
+        };
+
+        let mut flush_lookup = vec![Meta::WORST; 1 << 13].into_boxed_slice();
+        let mut ranks = Vec::with_capacity(7);
+        for (rank_bits, meta) in flush_lookup.iter_mut().enumerate() {
+            if !(5..=7).contains(&rank_bits.count_ones()) {
+                continue;
+            }
+            ranks.clear();
+            ranks.extend((0..13).filter(|rank| rank_bits & 1 << rank != 0));
+            *meta = best(&mut utils::const_combos::<_, 5>(&ranks).map(|combo| {
+                table.flush_lookup[&combo.iter().fold(1, |acc, &rank| acc * PRIMES[rank])]
+            }));
+        }
+
+        let mut unsuited_lookup = vec![Meta::WORST; MULTISETS].into_boxed_slice();
+        // Every non-decreasing sequence of seven ranks, with no rank more than
+        // four times
+        let mut ranks = [0; 7];
+        loop {
+            let mut rank_counts = [0; 13];
+            ranks
+                .iter()
+                .for_each(|&rank| rank_counts[rank as usize] += 1);
+            if rank_counts.iter().all(|&count| count <= 4) {
+                unsuited_lookup[multiset_index(&rank_counts)] =
+                    best(&mut utils::const_combos::<_, 5>(&ranks).map(|combo| {
+                        let product = combo
+                            .iter()
+                            .fold(1, |acc, &rank| acc * PRIMES[rank as usize]);
+                        table.unsuited_lookup[&product]
+                    }));
+            }
+            match ranks.iter().rposition(|&rank| rank < 12) {
+                Some(i) => {
+                    let next = ranks[i] + 1;
+                    ranks[i..].iter_mut().for_each(|rank| *rank = next);
+                }
+                None => break,
+            }
+        }
+
+        Self {
+            flush_lookup,
+            unsuited_lookup,
+        }
+    }
+
+    /// Evaluate a hand of exactly seven cards, finding the best five-card hand
+    /// among them.
+    ///
+    /// # Errors
+    ///
+    /// This function will fail if there aren't exactly seven cards, or if not
+    /// all the cards passed in are unique. See [`EvalError`] for more.
+    pub fn evaluate<C: AsRef<[Card]>>(&self, cards: C) -> Result<Eval, EvalError> {
+        let cards = cards.as_ref();
+        match *cards {
+            [a, b, c, d, e, f, g] => self
+                .seven([a, b, c, d, e, f, g])
+                .ok_or_else(|| EvalError::cards_not_unique(cards)),
+            _ => Err(EvalError::InvalidHandSize(cards.len())),
+        }
+    }
+
+    /// Evaluate seven cards, or return `None` if they aren't all unique.
+    fn seven(&self, cards: [Card; 7]) -> Option<Eval> {
+        // Count each suit in its own byte, and collect the rank bits of each
+        // suit, straight from the card encoding
+        let mut seen = 0u64;
+        let mut suit_counts = 0u32;
+        let mut suit_rank_bits = [0u16; 4];
+        let mut rank_counts = [0u8; 13];
+        for card in cards {
+            let unique = card.unique_integer();
+            let suit = ((unique >> 12) & 0xF).trailing_zeros();
+            let rank = (unique >> 8) & 0xF;
+            rank_counts[rank as usize] += 1;
+            seen |= 1 << (rank as u32 * 4 + suit);
+            suit_counts += 1 << (8 * suit);
+            suit_rank_bits[suit as usize] |= (unique >> 16) as u16;
+        }
+        if seen.count_ones() != 7 {
+            return None;
+        }
+
+        // At most one suit can have five or more of seven cards
+        let meta = match suit_counts
+            .to_le_bytes()
+            .iter()
+            .position(|&count| count >= 5)
+        {
+            Some(suit) => self.flush_lookup[suit_rank_bits[suit] as usize],
+            None => self.unsuited_lookup[multiset_index(&rank_counts)],
+        };
+        Some(Eval(meta))
+    }
+}
+
+impl Default for SevenCardEvaluator {
+    fn default() -> Self { Self::new() }
+}
+
+/// The index of a multiset of seven ranks, given as how many times each rank
+/// appears.
+fn multiset_index(rank_counts: &[u8; 13]) -> usize {
+    let mut index = 0;
+    let mut lower = 0;
+    for (rank, &count) in rank_counts.iter().enumerate() {
+        index += OFFSETS[rank][lower][count as usize] as usize;
+        lower += count as usize;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use lazy_static::lazy_static;
+
+    use super::*;
+    use crate::{deck, evaluate::tests::EVALUATOR, CardSet, Rank, Suit};
+
+    lazy_static! {
+        static ref SEVEN_CARD_EVALUATOR: SevenCardEvaluator = SevenCardEvaluator::new();
+    }
+
+    #[test]
+    fn multiset_indices_are_dense() {
+        let mut seen = vec![false; MULTISETS];
+        let mut ranks = [0; 7];
+        loop {
+            let mut rank_counts = [0; 13];
+            ranks
+                .iter()
+                .for_each(|&rank| rank_counts[rank as usize] += 1);
+            let index = multiset_index(&rank_counts);
+            assert!(!seen[index]);
+            seen[index] = true;
+            match ranks.iter().rposition(|&rank| rank < 12) {
+                Some(i) => {
+                    let next = ranks[i] + 1;
+                    ranks[i..].iter_mut().for_each(|rank| *rank = next);
+                }
+                None => break,
+            }
+        }
+        assert!(seen.into_iter().all(|seen| seen));
+    }
+
+    #[test]
+    fn matches_evaluator_for_flushes() {
+        // Every hand from two suits of nine ranks, which includes hands with
+        // five, six and seven cards of one suit
+        let cards = deck::generate()
+            .filter(|card| card.rank() >= Rank::Six)
+            .filter(|card| matches!(card.suit(), Suit::Spades | Suit::Hearts))
+            .collect::<Vec<_>>();
+        for hand in utils::const_combos::<_, 7>(&cards) {
+            assert_eq!(
+                SEVEN_CARD_EVALUATOR.evaluate(hand),
+                EVALUATOR.evaluate(hand),
+                "{:?}",
+                hand
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn matches_evaluator_for_random_hands() {
+        for seed in 0..20_000 {
+            let hand = deck::Deck::from_seed(seed).draw(7).unwrap();
+            assert_eq!(
+                SEVEN_CARD_EVALUATOR.evaluate(&hand),
+                EVALUATOR.evaluate(&hand),
+                "{:?}",
+                hand
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        let cards = deck::generate().collect::<Vec<_>>();
+        assert_eq!(
+            SEVEN_CARD_EVALUATOR.evaluate(&cards[..6]),
+            Err(EvalError::InvalidHandSize(6))
+        );
+        assert_eq!(
+            SEVEN_CARD_EVALUATOR.evaluate(&cards[..8]),
+            Err(EvalError::InvalidHandSize(8))
+        );
+        let mut hand = cards[..7].to_vec();
+        hand[6] = hand[0];
+        assert_eq!(
+            SEVEN_CARD_EVALUATOR.evaluate(&hand),
+            Err(EvalError::CardsNotUnique(CardSet::from([hand[0]])))
+        );
+    }
+}
@@ -0,0 +1,642 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use rustc_hash::{FxBuildHasher, FxHashMap};
+
+use crate::{constants::PRIMES, evaluate::utils, Card, EvalClass, EvalError, Rank};
+
+/// The ranks in a short deck, from best to worst.
+const SHORT_DECK_RANKS: [Rank; 9] = [
+    Rank::Ace,
+    Rank::King,
+    Rank::Queen,
+    Rank::Jack,
+    Rank::Ten,
+    Rank::Nine,
+    Rank::Eight,
+    Rank::Seven,
+    Rank::Six,
+];
+
+/// The number of distinct short-deck hands, with no two hands tied.
+const DISTINCT_HANDS: usize = 1404;
+
+/// The rules that decide how short-deck hands are ranked, where they differ
+/// from standard poker. Every rule set agrees that A-6-7-8-9 is a straight,
+/// the lowest one.
+///
+/// The [`Default`] rules are the ones most commonly played: flushes beat full
+/// houses, because a flush is harder to make than a full house with only nine
+/// ranks in the deck, and straights still beat three of a kind.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ShortDeckRules {
+    /// Whether a flush beats a full house, rather than the other way around.
+    pub flush_beats_full_house: bool,
+    /// Whether three of a kind beats a straight, rather than the other way
+    /// around.
+    pub trips_beat_straight: bool,
+}
+
+impl ShortDeckRules {
+    /// The most common rules, where a flush beats a full house and a straight
+    /// beats three of a kind.
+    pub const STANDARD: Self = Self {
+        flush_beats_full_house: true,
+        trips_beat_straight: false,
+    };
+    /// Rules where a flush beats a full house, and three of a kind also beats a
+    /// straight.
+    pub const TRIPS_BEAT_STRAIGHTS: Self = Self {
+        flush_beats_full_house: true,
+        trips_beat_straight: true,
+    };
+
+    /// Compare two classes of hands by these rules. The derived [`Ord`] of
+    /// [`EvalClass`] follows standard poker, so use this to sort or compare
+    /// the classes of short-deck hands instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{EvalClass, Rank, ShortDeckRules};
+    ///
+    /// let full_house = EvalClass::FullHouse {
+    ///     trips: Rank::Ace,
+    ///     pair: Rank::King,
+    /// };
+    /// let flush = EvalClass::Flush {
+    ///     high_rank: Rank::Jack,
+    /// };
+    /// assert!(full_house > flush);
+    ///
+    /// let mut classes = vec![flush, full_house];
+    /// classes.sort_by(|a, b| ShortDeckRules::STANDARD.cmp_class(*a, *b));
+    /// assert_eq!(classes, [full_house, flush]);
+    /// ```
+    pub fn cmp_class(self, a: EvalClass, b: EvalClass) -> Ordering {
+        self.class_order(a)
+            .cmp(&self.class_order(b))
+            .then_with(|| a.cmp(&b))
+    }
+
+    /// Where a class of hands falls among the others by these rules, from 0
+    /// for a high card to 8 for a straight flush.
+    fn class_order(self, class: EvalClass) -> u8 {
+        match class {
+            EvalClass::HighCard { .. } => 0,
+            EvalClass::Pair { .. } => 1,
+            EvalClass::TwoPair { .. } => 2,
+            EvalClass::ThreeOfAKind { .. } if self.trips_beat_straight => 4,
+            EvalClass::ThreeOfAKind { .. } => 3,
+            EvalClass::Straight { .. } if self.trips_beat_straight => 3,
+            EvalClass::Straight { .. } => 4,
+            EvalClass::Flush { .. } if self.flush_beats_full_house => 6,
+            EvalClass::Flush { .. } => 5,
+            EvalClass::FullHouse { .. } if self.flush_beats_full_house => 5,
+            EvalClass::FullHouse { .. } => 6,
+            EvalClass::FourOfAKind { .. } => 7,
+            EvalClass::StraightFlush { .. } => 8,
+        }
+    }
+}
+
+impl Default for ShortDeckRules {
+    fn default() -> Self { Self::STANDARD }
+}
+
+/// The result of a successful short-deck hand evaluation, returned from
+/// [`ShortDeckEvaluator::evaluate`]. When printed in [`Display`] format, shows
+/// the name of the poker hand, like [`Eval`] does.
+///
+/// Instances of `ShortDeckEval` implement [`Ord`] such that better hands are
+/// greater, according to the [`ShortDeckRules`] of the evaluator that
+/// produced them. Comparing results from evaluators with different rules is
+/// meaningless.
+///
+/// [`Display`]: fmt::Display
+/// [`Eval`]: crate::Eval
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ShortDeckEval {
+    // 1 is the best hand, and `DISTINCT_HANDS` is the worst
+    hand_rank: u16,
+    class: EvalClass,
+    ranks: [Rank; 5],
+}
+
+impl ShortDeckEval {
+    /// Obtain the class of this poker hand, as with [`Eval::class`]. Classes
+    /// compare as in standard poker, so compare them with
+    /// [`ShortDeckRules::cmp_class`] to follow the rules of the evaluator.
+    ///
+    /// [`Eval::class`]: crate::Eval::class
+    pub const fn class(self) -> EvalClass { self.class }
+
+    /// Recover the ranks of all five cards in this hand, ordered by
+    /// significance, as with [`Eval::ranks`]. In an A-6-7-8-9 straight, the
+    /// ace comes last.
+    ///
+    /// [`Eval::ranks`]: crate::Eval::ranks
+    pub const fn ranks(self) -> [Rank; 5] { self.ranks }
+
+    /// Check whether this hand is better than another.
+    pub fn is_better_than(self, other: Self) -> bool { self > other }
+
+    /// Check whether this hand is worse than another.
+    pub fn is_worse_than(self, other: Self) -> bool { self < other }
+}
+
+impl Hash for ShortDeckEval {
+    // The class and ranks follow from the hand rank
+    fn hash<H: Hasher>(&self, state: &mut H) { self.hand_rank.hash(state); }
+}
+
+impl PartialOrd for ShortDeckEval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for ShortDeckEval {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // A lower hand rank is a better hand
+        other.hand_rank.cmp(&self.hand_rank)
+    }
+}
+
+impl fmt::Display for ShortDeckEval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.class.fmt(f) }
+}
+
+/// Evaluates short-deck (6+) Hold'em hands, played with a 36-card deck that has
+/// no deuces, treys, fours or fives.
+///
+/// Aces can still play low in a straight, as A-6-7-8-9. Because the deck has
+/// fewer ranks, the order of some hands changes too, as described by the
+/// [`ShortDeckRules`] given to [`ShortDeckEvaluator::new`]. Like the
+/// [`Evaluator`], a `ShortDeckEvaluator` builds its lookup tables when it is
+/// created, so create one early and reuse it.
+///
+/// # Example
+///
+/// ```
+/// use poker::{cards, Card, EvalClass, Rank, ShortDeckEvaluator, ShortDeckRules};
+///
+/// let eval = ShortDeckEvaluator::new(ShortDeckRules::default());
+/// let straight: Vec<Card> = cards!("Ac 6d 7h 8s 9c")
+///     .try_collect()
+///     .expect("couldn't parse cards");
+/// let flush: Vec<Card> = cards!("Ac Kc 6c 8c Tc")
+///     .try_collect()
+///     .expect("couldn't parse cards");
+/// let full_house: Vec<Card> = cards!("Ac Ad Ah Kc Kd")
+///     .try_collect()
+///     .expect("couldn't parse cards");
+///
+/// let straight = eval.evaluate(straight).expect("couldn't evaluate hand");
+/// let flush = eval.evaluate(flush).expect("couldn't evaluate hand");
+/// let full_house = eval.evaluate(full_house).expect("couldn't evaluate hand");
+/// assert!(matches!(
+///     straight.class(),
+///     EvalClass::Straight {
+///         high_rank: Rank::Nine
+///     }
+/// ));
+/// assert!(flush.is_better_than(full_house));
+/// ```
+///
+/// [`Evaluator`]: crate::Evaluator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortDeckEvaluator {
+    rules: ShortDeckRules,
+    flush_lookup: FxHashMap<i32, ShortDeckEval>,
+    unsuited_lookup: FxHashMap<i32, ShortDeckEval>,
+}
+
+impl ShortDeckEvaluator {
+    /// Create a new [`ShortDeckEvaluator`] that ranks hands by the given rules.
+    pub fn new(rules: ShortDeckRules) -> Self {
+        let mut evaluator = Self {
+            rules,
+            flush_lookup: FxHashMap::with_capacity_and_hasher(126, FxBuildHasher),
+            unsuited_lookup: FxHashMap::with_capacity_and_hasher(
+                DISTINCT_HANDS - 126,
+                FxBuildHasher,
+            ),
+        };
+        evaluator.build_tables();
+        evaluator
+    }
+
+    /// The rules this evaluator ranks hands by.
+    pub const fn rules(&self) -> ShortDeckRules { self.rules }
+
+    /// Evaluate a short-deck hand. Given more than five cards, the best hand
+    /// that can be made from any five of them is returned.
+    ///
+    /// # Errors
+    ///
+    /// This function will fail if not all the cards passed in are unique, if
+    /// any card is a five or lower, or if the total number of cards is less
+    /// than five. See [`EvalError`] for more.
+    pub fn evaluate<C: AsRef<[Card]>>(&self, cards: C) -> Result<ShortDeckEval, EvalError> {
+        let cards = cards.as_ref();
+        if !utils::all_unique(cards) {
+            return Err(EvalError::cards_not_unique(cards));
+        }
+        if let Some(&card) = cards.iter().find(|card| card.rank() < Rank::Six) {
+            return Err(EvalError::CardNotInDeck(card));
+        }
+        if cards.len() < 5 {
+            return Err(EvalError::InvalidHandSize(cards.len()));
+        }
+        Ok(utils::const_combos::<_, 5>(cards)
+            .map(|hand| self.five(hand))
+            .max()
+            .expect("there is always at least one combination"))
+    }
+
+    fn five(&self, cards: [Card; 5]) -> ShortDeckEval {
+        let uniques = cards.map(Card::unique_integer);
+        let detect_flush = uniques.into_iter().fold(0xF000, |acc, x| acc & x) != 0;
+        let prime = utils::prime_product_from_hand(cards);
+        if detect_flush {
+            self.flush_lookup[&prime]
+        } else {
+            self.unsuited_lookup[&prime]
+        }
+    }
+
+    /// Build both lookup tables. As in [`LookupTable::new`], each class of
+    /// hands is listed from best to worst, and then the classes are placed in
+    /// the order the rules call for to hand out hand ranks.
+    ///
+    /// [`LookupTable::new`]: crate::evaluate::lookup_table::LookupTable::new
+    fn build_tables(&mut self) {
+        // (class, ranks by significance, suited)
+        type Hands = Vec<(EvalClass, [Rank; 5], bool)>;
+
+        let mut straight_flushes: Hands = Vec::with_capacity(6);
+        let mut flushes: Hands = Vec::with_capacity(120);
+        let mut straights: Hands = Vec::with_capacity(6);
+        let mut high_cards: Hands = Vec::with_capacity(120);
+        // Every set of five different ranks, from best to worst
+        for ranks in utils::const_combos::<_, 5>(&SHORT_DECK_RANKS) {
+            match straight_high_rank(ranks) {
+                Some(high_rank) => {
+                    let mut ranks = ranks;
+                    if high_rank == Rank::Nine {
+                        // The ace plays low
+                        ranks.rotate_left(1);
+                    }
+                    straight_flushes.push((EvalClass::StraightFlush { high_rank }, ranks, true));
+                    straights.push((EvalClass::Straight { high_rank }, ranks, false));
+                }
+                None => {
+                    let high_rank = ranks[0];
+                    flushes.push((EvalClass::Flush { high_rank }, ranks, true));
+                    high_cards.push((EvalClass::HighCard { high_rank }, ranks, false));
+                }
+            }
+        }
+        // The A-6-7-8-9 straight comes up early among the ace-high sets of
+        // ranks, but it is the worst straight
+        straight_flushes.sort_by_key(|&(_, ranks, _)| std::cmp::Reverse(ranks[0]));
+        straights.sort_by_key(|&(_, ranks, _)| std::cmp::Reverse(ranks[0]));
+
+        let others = |excluded: &[Rank]| {
+            SHORT_DECK_RANKS
+                .iter()
+                .copied()
+                .filter(|rank| !excluded.contains(rank))
+                .collect::<Vec<_>>()
+        };
+
+        let mut quads: Hands = Vec::with_capacity(72);
+        let mut full_houses: Hands = Vec::with_capacity(72);
+        let mut trips: Hands = Vec::with_capacity(252);
+        let mut pairs: Hands = Vec::with_capacity(504);
+        for &rank in &SHORT_DECK_RANKS {
+            let kickers = others(&[rank]);
+            for &kicker in &kickers {
+                quads.push((
+                    EvalClass::FourOfAKind { quads: rank },
+                    [rank, rank, rank, rank, kicker],
+                    false,
+                ));
+                full_houses.push((
+                    EvalClass::FullHouse {
+                        trips: rank,
+                        pair: kicker,
+                    },
+                    [rank, rank, rank, kicker, kicker],
+                    false,
+                ));
+            }
+            for [first, second] in utils::const_combos::<_, 2>(&kickers) {
+                trips.push((
+                    EvalClass::ThreeOfAKind { trips: rank },
+                    [rank, rank, rank, first, second],
+                    false,
+                ));
+            }
+            for [first, second, third] in utils::const_combos::<_, 3>(&kickers) {
+                pairs.push((
+                    EvalClass::Pair { pair: rank },
+                    [rank, rank, first, second, third],
+                    false,
+                ));
+            }
+        }
+
+        let mut two_pairs: Hands = Vec::with_capacity(252);
+        for [first_pair, second_pair] in utils::const_combos::<_, 2>(&SHORT_DECK_RANKS) {
+            for kicker in others(&[first_pair, second_pair]) {
+                two_pairs.push((
+                    EvalClass::TwoPair {
+                        first_pair,
+                        second_pair,
+                    },
+                    [first_pair, first_pair, second_pair, second_pair, kicker],
+                    false,
+                ));
+            }
+        }
+
+        let (flushes, full_houses) = if self.rules.flush_beats_full_house {
+            (flushes, full_houses)
+        } else {
+            (full_houses, flushes)
+        };
+        let (straights, trips) = if self.rules.trips_beat_straight {
+            (trips, straights)
+        } else {
+            (straights, trips)
+        };
+        let classes = [
+            straight_flushes,
+            quads,
+            flushes,
+            full_houses,
+            straights,
+            trips,
+            two_pairs,
+            pairs,
+            high_cards,
+        ];
+
+        let hands = classes.into_iter().flatten();
+        for (hand_rank, (class, ranks, suited)) in (1..).zip(hands) {
+            let product = ranks
+                .iter()
+                .fold(1, |acc, &rank| acc * PRIMES[rank as usize]);
+            let table = if suited {
+                &mut self.flush_lookup
+            } else {
+                &mut self.unsuited_lookup
+            };
+            table.insert(
+                product,
+                ShortDeckEval {
+                    hand_rank,
+                    class,
+                    ranks,
+                },
+            );
+        }
+        debug_assert_eq!(
+            self.flush_lookup.len() + self.unsuited_lookup.len(),
+            DISTINCT_HANDS
+        );
+    }
+}
+
+/// The high rank of the straight made by five different short-deck ranks,
+/// given from highest to lowest, or `None` if they don't make a straight.
+fn straight_high_rank(ranks: [Rank; 5]) -> Option<Rank> {
+    if ranks == [Rank::Ace, Rank::Nine, Rank::Eight, Rank::Seven, Rank::Six] {
+        Some(Rank::Nine)
+    } else if ranks[0] as u8 - ranks[4] as u8 == 4 {
+        Some(ranks[0])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deck;
+
+    fn evaluate(eval: &ShortDeckEvaluator, cards: &str) -> ShortDeckEval {
+        let cards = Card::parse_to_iter(cards.split_whitespace())
+            .try_collect::<Vec<_>>()
+            .unwrap();
+        eval.evaluate(cards).unwrap()
+    }
+
+    #[test]
+    fn every_hand_is_distinct() {
+        let eval = ShortDeckEvaluator::new(ShortDeckRules::default());
+        assert_eq!(eval.flush_lookup.len(), 126);
+        assert_eq!(eval.unsuited_lookup.len(), DISTINCT_HANDS - 126);
+
+        let deck = deck::generate_short_deck().collect::<Vec<_>>();
+        let mut seen = std::collections::HashSet::new();
+        for hand in utils::const_combos::<_, 5>(&deck) {
+            let result = eval.evaluate(hand).unwrap();
+            let product = result
+                .ranks()
+                .iter()
+                .fold(1, |acc, &rank| acc * PRIMES[rank as usize]);
+            assert_eq!(product, utils::prime_product_from_hand(hand));
+            seen.insert(result.hand_rank);
+        }
+        assert_eq!(seen.len(), DISTINCT_HANDS);
+    }
+
+    #[test]
+    fn standard_ordering() {
+        let eval = ShortDeckEvaluator::new(ShortDeckRules::STANDARD);
+        let ordered = [
+            ("Tc Jc Qc Kc Ac", "Royal flush"),
+            ("Ac 6c 7c 8c 9c", "Straight flush, nine-high"),
+            ("6c 6d 6h 6s 7c", "Four of a kind, sixes"),
+            ("Ac Kc Qc Jc 9c", "Flush, ace-high"),
+            ("7c 6c 8c 9c Jc", "Flush, jack-high"),
+            ("Ac Ad Ah Kc Kd", "Full house, aces over kings"),
+            ("Ac Kd Qh Js Tc", "Straight, ace-high"),
+            ("Ac 6d 7h 8s 9c", "Straight, nine-high"),
+            ("Ac Ad Ah Kc Qd", "Three of a kind, aces"),
+            ("7c 7d 6h 6s 8c", "Two pair, sevens and sixes"),
+            ("6c 6d 7h 8s 9c", "Pair, sixes"),
+            ("Ac Kd Qh Js 9c", "High card, ace"),
+            ("6c 7d 8h 9s Jc", "High card, jack"),
+        ];
+        let results = ordered
+            .iter()
+            .map(|&(hand, name)| {
+                let result = evaluate(&eval, hand);
+                assert_eq!(result.to_string(), name);
+                result
+            })
+            .collect::<Vec<_>>();
+        assert!(results
+            .windows(2)
+            .all(|pair| pair[0].is_better_than(pair[1])));
+        assert_eq!(results[0].hand_rank, 1);
+        assert_eq!(
+            results[results.len() - 1].hand_rank as usize,
+            DISTINCT_HANDS
+        );
+        assert_eq!(
+            evaluate(&eval, "Ac 6d 7h 8s 9c").ranks(),
+            [Rank::Nine, Rank::Eight, Rank::Seven, Rank::Six, Rank::Ace]
+        );
+    }
+
+    #[test]
+    fn configurable_ordering() {
+        let hands = [
+            "Ac Kc Qc Jc 9c",
+            "6c 6d 6h 7s 7c",
+            "6c 7d 8h 9s Tc",
+            "Ac Ad Ah Kc Qd",
+        ];
+        let results = |rules| {
+            let eval = ShortDeckEvaluator::new(rules);
+            assert_eq!(eval.rules(), rules);
+            hands
+                .iter()
+                .map(|hand| evaluate(&eval, hand))
+                .collect::<Vec<_>>()
+        };
+
+        let standard = results(ShortDeckRules::STANDARD);
+        assert!(standard[0] > standard[1] && standard[2] > standard[3]);
+
+        let trips_first = results(ShortDeckRules::TRIPS_BEAT_STRAIGHTS);
+        assert!(trips_first[0] > trips_first[1] && trips_first[3] > trips_first[2]);
+
+        let classic = results(ShortDeckRules {
+            flush_beats_full_house: false,
+            trips_beat_straight: false,
+        });
+        assert!(classic[1] > classic[0] && classic[2] > classic[3]);
+        // Either way, both beat a straight
+        assert!(classic[0] > classic[2] && classic[1] > classic[2]);
+    }
+
+    #[test]
+    fn classes_sort_by_rules() {
+        // From best to worst by the classic rules, which match standard poker
+        let classes = [
+            EvalClass::StraightFlush {
+                high_rank: Rank::Nine,
+            },
+            EvalClass::FourOfAKind { quads: Rank::Six },
+            EvalClass::FullHouse {
+                trips: Rank::Ace,
+                pair: Rank::King,
+            },
+            EvalClass::Flush {
+                high_rank: Rank::Ace,
+            },
+            EvalClass::Flush {
+                high_rank: Rank::Jack,
+            },
+            EvalClass::Straight {
+                high_rank: Rank::Ace,
+            },
+            EvalClass::Straight {
+                high_rank: Rank::Nine,
+            },
+            EvalClass::ThreeOfAKind { trips: Rank::Six },
+            EvalClass::TwoPair {
+                first_pair: Rank::Seven,
+                second_pair: Rank::Six,
+            },
+            EvalClass::Pair { pair: Rank::Ace },
+            EvalClass::HighCard {
+                high_rank: Rank::Jack,
+            },
+        ];
+        let sorted = |rules: ShortDeckRules| {
+            let mut sorted = classes.to_vec();
+            sorted.sort_by(|a, b| rules.cmp_class(*b, *a));
+            sorted
+        };
+
+        let standard = sorted(ShortDeckRules::STANDARD);
+        assert_eq!(&standard[2..5], [classes[3], classes[4], classes[2]]);
+        assert_eq!(&standard[5..8], &classes[5..8]);
+        let trips_first = sorted(ShortDeckRules::TRIPS_BEAT_STRAIGHTS);
+        assert_eq!(&trips_first[2..5], &standard[2..5]);
+        assert_eq!(&trips_first[5..8], [classes[7], classes[5], classes[6]]);
+        let classic = sorted(ShortDeckRules {
+            flush_beats_full_house: false,
+            trips_beat_straight: false,
+        });
+        assert_eq!(classic, classes);
+        for rules in [
+            ShortDeckRules::STANDARD,
+            ShortDeckRules::TRIPS_BEAT_STRAIGHTS,
+        ] {
+            assert_eq!(&sorted(rules)[..2], &classes[..2]);
+            assert_eq!(&sorted(rules)[8..], &classes[8..]);
+        }
+
+        // Classes agree with the evaluator's ordering
+        for rules in [
+            ShortDeckRules::STANDARD,
+            ShortDeckRules::TRIPS_BEAT_STRAIGHTS,
+        ] {
+            let eval = ShortDeckEvaluator::new(rules);
+            let straight = evaluate(&eval, "Ac 6d 7h 8s 9c");
+            let trips = evaluate(&eval, "Ac Ad Ah Kc Qd");
+            assert_eq!(
+                rules.cmp_class(straight.class(), trips.class()),
+                straight.cmp(&trips)
+            );
+        }
+    }
+
+    #[test]
+    fn best_five_of_seven() {
+        let eval = ShortDeckEvaluator::new(ShortDeckRules::default());
+        let result = evaluate(&eval, "Ah Ad Ac Kh Kd 9h 6h");
+        assert!(matches!(
+            result.class(),
+            EvalClass::FullHouse {
+                trips: Rank::Ace,
+                pair: Rank::King
+            }
+        ));
+        let result = evaluate(&eval, "Ah Ad Ac Kh Th 9h 6h");
+        assert!(matches!(
+            result.class(),
+            EvalClass::Flush {
+                high_rank: Rank::Ace
+            }
+        ));
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        let eval = ShortDeckEvaluator::new(ShortDeckRules::default());
+        let cards = deck::generate().collect::<Vec<_>>();
+        assert_eq!(
+            eval.evaluate(&cards[..5]),
+            Err(EvalError::CardNotInDeck(cards[0]))
+        );
+        let short = deck::generate_short_deck().collect::<Vec<_>>();
+        assert_eq!(
+            eval.evaluate(&short[..4]),
+            Err(EvalError::InvalidHandSize(4))
+        );
+        assert!(matches!(
+            eval.evaluate([short[0], short[0], short[1], short[2], short[3]]),
+            Err(EvalError::CardsNotUnique(_))
+        ));
+    }
+}
@@ -4,8 +4,8 @@
 //! a static lookup table, built into the library.
 //!
 //! Because the `static` lookup table doesn't allocate any memory on the heap,
-//! this module may become the foundation for providing `no_std` support in the
-//! future.
+//! this module is also available without the `std` feature, where it is the
+//! way to evaluate hands.
 //!
 //! **Warning:** Enabling the `static_lookup` feature will greatly increase the
 //! size of the resulting library.
@@ -16,19 +16,12 @@ use super::{
     evaluation::{self, Evaluation},
     meta::Meta,
 };
-use crate::{Card, Eval, EvalError};
+use crate::{Card, CardSet, DeuceSevenLow, Eval, EvalError};
 
-// This module includes the automatically generated code, fetched at build time.
+// This module includes the automatically generated code, built from
+// `LookupTable::new` by the build script.
 mod statics {
-    #[cfg(not(docsrs))]
     include!(concat!(env!("OUT_DIR"), "/codegen.rs"));
-
-    // Empty maps for docs.rs
-    #[cfg(docsrs)]
-    pub static FLUSH_LOOKUP: ::phf::Map<i32, crate::evaluate::meta::Meta> = ::phf::Map::new();
-
-    #[cfg(docsrs)]
-    pub static UNSUITED_LOOKUP: ::phf::Map<i32, crate::evaluate::meta::Meta> = ::phf::Map::new();
 }
 
 // Helper struct for implementing Evaluation without having an actual struct
@@ -40,6 +33,10 @@ impl Evaluation for StaticEvaluator {
     fn flush_lookup(&self) -> &Self::Lookup { &statics::FLUSH_LOOKUP }
 
     fn unsuited_lookup(&self) -> &Self::Lookup { &statics::UNSUITED_LOOKUP }
+
+    fn dense_lookup(&self) -> &[i16] { &statics::DENSE_LOOKUP }
+
+    fn meta_lookup(&self) -> &[Meta] { &statics::META_LOOKUP }
 }
 
 /// Evaluate a hand using the static lookup table bundled with the library.
@@ -104,15 +101,188 @@ pub fn evaluate<C: AsRef<[Card]>>(cards: C) -> Result<Eval, EvalError> {
     evaluation::evaluate(&StaticEvaluator, cards)
 }
 
+/// Evaluate a hand stored in a [`CardSet`] using the static lookup table
+/// bundled with the library. Like [`Evaluator::evaluate_set`], this skips
+/// checking the cards for uniqueness.
+///
+/// # Errors
+///
+/// This function will fail if the set has less than five cards. See
+/// [`EvalError`] for more.
+///
+/// [`Evaluator::evaluate_set`]: crate::Evaluator::evaluate_set
+pub fn evaluate_set(cards: CardSet) -> Result<Eval, EvalError> {
+    evaluation::evaluate_set(&StaticEvaluator, cards)
+}
+
+/// Evaluate many hands of the same size at once using the static lookup table
+/// bundled with the library, writing the result for `hands[i]` into `out[i]`.
+/// Like [`Evaluator::evaluate_many`], every hand is checked for uniqueness up
+/// front.
+///
+/// # Errors
+///
+/// This function will fail if the hands have less than five cards, or if not
+/// all the cards of some hand are unique. See [`EvalError`] for more.
+///
+/// # Panics
+///
+/// This function will panic if `out` and `hands` have different lengths.
+///
+/// [`Evaluator::evaluate_many`]: crate::Evaluator::evaluate_many
+pub fn evaluate_many<const N: usize>(
+    hands: &[[Card; N]],
+    out: &mut [Eval],
+) -> Result<(), EvalError> {
+    evaluation::evaluate_many(&StaticEvaluator, hands, out)
+}
+
+/// Like [`evaluate_many`], but skip checking the hands for uniqueness, as in
+/// [`Evaluator::evaluate_many_unique`].
+///
+/// # Errors
+///
+/// This function will fail if the hands have less than five cards. See
+/// [`EvalError`] for more.
+///
+/// # Panics
+///
+/// This function will panic if `out` and `hands` have different lengths.
+/// A hand with duplicate cards has no meaningful result, and its result may be
+/// any [`Eval`].
+///
+/// [`Evaluator::evaluate_many_unique`]: crate::Evaluator::evaluate_many_unique
+pub fn evaluate_many_unique<const N: usize>(
+    hands: &[[Card; N]],
+    out: &mut [Eval],
+) -> Result<(), EvalError> {
+    evaluation::evaluate_many_unique(&StaticEvaluator, hands, out)
+}
+
+/// Like [`evaluate_many`], but split the hands between the threads of the
+/// current [rayon] thread pool, as in [`Evaluator::par_evaluate_many`]. This
+/// function needs the `rayon` feature.
+///
+/// # Errors
+///
+/// This function will fail if the hands have less than five cards, or if not
+/// all the cards of some hand are unique. See [`EvalError`] for more.
+///
+/// # Panics
+///
+/// This function will panic if `out` and `hands` have different lengths.
+///
+/// [`Evaluator::par_evaluate_many`]: crate::Evaluator::par_evaluate_many
+#[cfg(feature = "rayon")]
+pub fn par_evaluate_many<const N: usize>(
+    hands: &[[Card; N]],
+    out: &mut [Eval],
+) -> Result<(), EvalError> {
+    evaluation::par_evaluate_many(&StaticEvaluator, hands, out)
+}
+
+/// Like [`par_evaluate_many`], but skip checking the hands for uniqueness, as
+/// in [`Evaluator::evaluate_many_unique`]. This function needs the `rayon`
+/// feature.
+///
+/// # Errors
+///
+/// This function will fail if the hands have less than five cards. See
+/// [`EvalError`] for more.
+///
+/// # Panics
+///
+/// This function will panic if `out` and `hands` have different lengths.
+/// A hand with duplicate cards has no meaningful result, and its result may be
+/// any [`Eval`].
+///
+/// [`Evaluator::evaluate_many_unique`]: crate::Evaluator::evaluate_many_unique
+#[cfg(feature = "rayon")]
+pub fn par_evaluate_many_unique<const N: usize>(
+    hands: &[[Card; N]],
+    out: &mut [Eval],
+) -> Result<(), EvalError> {
+    evaluation::par_evaluate_many_unique(&StaticEvaluator, hands, out)
+}
+
+/// Evaluate a hand using the static lookup table bundled with the library,
+/// like [`evaluate`], but also return the five cards that make up the best
+/// hand. The cards are ordered by significance, as described in
+/// [`Evaluator::evaluate_best_hand`].
+///
+/// # Errors
+///
+/// This function will fail if the total number of cards is less than five,
+/// or if not all the cards passed in are unique. See
+/// [`EvalError`] for more.
+///
+/// # Example
+///
+/// ```
+/// use poker::{cards, evaluate::static_lookup, Card};
+///
+/// let hand: Vec<Card> = cards!("4d Jh 9c 4s Jd 2c Ac")
+///     .try_collect()
+///     .expect("couldn't parse cards");
+/// let (result, best) = static_lookup::evaluate_best_hand(hand).expect("couldn't evaluate hand");
+/// assert!(result.is_two_pair());
+/// let expected: Vec<Card> = cards!("Jh Jd 4d 4s Ac")
+///     .try_collect()
+///     .expect("couldn't parse cards");
+/// assert_eq!(best, *expected);
+/// ```
+///
+/// [`Evaluator::evaluate_best_hand`]: crate::Evaluator::evaluate_best_hand
+pub fn evaluate_best_hand<C: AsRef<[Card]>>(cards: C) -> Result<(Eval, [Card; 5]), EvalError> {
+    let cards = cards.as_ref();
+    evaluation::evaluate_best_hand(&StaticEvaluator, cards)
+}
+
+/// Evaluate an Omaha hand using the static lookup table bundled with the
+/// library. Like [`Evaluator::evaluate_omaha`], the hand must use exactly two
+/// of the `hole_cards` and exactly three cards from the `board`.
+///
+/// # Errors
+///
+/// This function will fail if there are fewer than 4 or more than 6 hole
+/// cards, if the board has fewer than 3 or more than 5 cards, or if not all
+/// the cards passed in are unique. See [`EvalError`] for more.
+///
+/// [`Evaluator::evaluate_omaha`]: crate::Evaluator::evaluate_omaha
+pub fn evaluate_omaha<H, B>(hole_cards: H, board: B) -> Result<Eval, EvalError>
+where
+    H: AsRef<[Card]>,
+    B: AsRef<[Card]>,
+{
+    evaluation::evaluate_omaha(&StaticEvaluator, hole_cards.as_ref(), board.as_ref())
+}
+
+/// Evaluate a hand for deuce-to-seven low using the static lookup table
+/// bundled with the library, like [`Evaluator::evaluate_deuce_to_seven`].
+///
+/// # Errors
+///
+/// This function will fail if the total number of cards is less than five,
+/// or if not all the cards passed in are unique. See [`EvalError`] for more.
+///
+/// [`Evaluator::evaluate_deuce_to_seven`]: crate::Evaluator::evaluate_deuce_to_seven
+pub fn evaluate_deuce_to_seven<C: AsRef<[Card]>>(cards: C) -> Result<DeuceSevenLow, EvalError> {
+    evaluation::evaluate_deuce_to_seven(&StaticEvaluator, cards.as_ref())
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
 
-    use super::{evaluate, statics::*};
+    use super::{
+        evaluate, evaluate_best_hand, evaluate_deuce_to_seven, evaluate_many, evaluate_omaha,
+        statics::*,
+    };
     use crate::{
         card::Card,
         deck,
         evaluate::{
+            eval::Eval,
             hand_rank::PokerHandRank,
             tests::{FiveCardHand, RepresentativeHand, SevenCardHand, SixCardHand, EVALUATOR},
             utils,
@@ -124,7 +294,7 @@ mod tests {
         let deck = deck::generate().collect::<Vec<_>>();
         let gen = utils::const_combos::<_, 5>(&deck);
         let evals = gen.fold(HashSet::with_capacity(7462), |mut ints, hand| {
-            ints.insert(evaluate(&hand).unwrap());
+            ints.insert(evaluate(hand).unwrap());
             ints
         });
         assert_eq!(evals.len(), 7462);
@@ -168,26 +338,76 @@ mod tests {
         representative_hand_evaluates_correctly::<SevenCardHand>();
     }
 
+    #[test]
+    fn best_hand_matches_dynamic() {
+        for &hand in SevenCardHand::ALL_HANDS {
+            let cards = Card::parse_to_iter(hand).try_collect::<Box<_>>().unwrap();
+            assert_eq!(
+                evaluate_best_hand(&cards).unwrap(),
+                EVALUATOR.evaluate_best_hand(&cards).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn evaluate_many_matches_dynamic() {
+        let deck = deck::generate().collect::<Vec<_>>();
+        let hands = utils::const_combos::<_, 6>(&deck[..20]).collect::<Vec<_>>();
+        let mut results = vec![Eval::WORST; hands.len()];
+        let mut expected = results.clone();
+        evaluate_many(&hands, &mut results).unwrap();
+        EVALUATOR.evaluate_many(&hands, &mut expected).unwrap();
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn omaha_matches_dynamic() {
+        // Every third card, to mix ranks and suits in a small sample
+        let cards = deck::generate().step_by(3).collect::<Vec<_>>();
+        let (hole_cards, boards) = cards.split_at(7);
+        for hole_cards in utils::const_combos::<_, 4>(hole_cards) {
+            for board in utils::const_combos::<_, 5>(boards) {
+                assert_eq!(
+                    evaluate_omaha(hole_cards, board).unwrap(),
+                    EVALUATOR.evaluate_omaha(hole_cards, board).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn deuce_to_seven_matches_dynamic() {
+        for &hand in SevenCardHand::ALL_HANDS {
+            let cards = Card::parse_to_iter(hand).try_collect::<Box<_>>().unwrap();
+            assert_eq!(
+                evaluate_deuce_to_seven(&cards),
+                EVALUATOR.evaluate_deuce_to_seven(&cards)
+            );
+        }
+    }
+
     #[test]
     fn ensure_identical_tables() {
         macro_rules! fail {
             () => {
-                "The dynamic and static lookup tables contain different data. This is a bug! The \
-                 static table may need to be regenerated."
+                "The dynamic and static lookup tables contain different data. This is a bug in the \
+                 build script that generates the static table."
             };
         }
 
         // Flushes
-        let fl = &EVALUATOR.0.flush_lookup;
+        let fl = &EVALUATOR.table.flush_lookup;
         for (key, value) in &FLUSH_LOOKUP {
             assert_eq!(&fl[key], value, fail!());
+            assert_eq!(fl[key].class(), value.class(), fail!());
         }
         assert_eq!(fl.len(), FLUSH_LOOKUP.len(), fail!());
 
         // Unsuited
-        let us = &EVALUATOR.0.unsuited_lookup;
+        let us = &EVALUATOR.table.unsuited_lookup;
         for (key, value) in &UNSUITED_LOOKUP {
             assert_eq!(&us[key], value, fail!());
+            assert_eq!(us[key].class(), value.class(), fail!());
         }
         assert_eq!(us.len(), UNSUITED_LOOKUP.len(), fail!());
     }
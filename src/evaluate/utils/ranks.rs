@@ -0,0 +1,282 @@
+//! Helpers that work with ranks and combinations, but never with whole cards.
+//!
+//! The build script compiles this module, along with the lookup table and the
+//! modules it depends on, to generate the static lookup table. Everything in
+//! here may only depend on those modules.
+
+use core::array;
+
+use crate::{
+    card::rank::Rank,
+    constants::{INT_RANKS, PRIMES},
+    evaluate::hand_rank,
+};
+
+#[derive(Debug, Clone, Copy)]
+struct Combinations<'a, T, const N: usize> {
+    data: &'a [T],
+    indices: [usize; N],
+    done: bool,
+}
+
+impl<'a, T, const N: usize> Combinations<'a, T, N> {
+    fn new(data: &'a [T]) -> Self {
+        let indices = array::from_fn(|index| index);
+        Self {
+            data,
+            indices,
+            done: false,
+        }
+    }
+}
+
+impl<'a, T: Copy, const N: usize> Iterator for Combinations<'a, T, N> {
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = self.indices.map(|i| self.data[i]);
+
+        for i in (0..N).rev() {
+            if i == 0 && self.indices[i] == self.data.len() - N + i {
+                self.done = true;
+            }
+
+            if self.indices[i] < self.data.len() - N + i {
+                self.indices[i] += 1;
+                for j in i + 1..N {
+                    self.indices[j] = self.indices[j - 1] + 1;
+                }
+                break;
+            }
+        }
+
+        Some(result)
+    }
+}
+
+/// Originally from <http://www-graphics.stanford.edu/~seander/bithacks.html#NextBitPermutation>.
+/// This differs from the implementation in Python because we use trailing
+/// zeroes. Only the lookup table uses this, which needs the `std` feature,
+/// unless it's compiled by the build script.
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
+#[derive(Debug, Clone, Copy)]
+pub struct BitSequence {
+    bits: i16,
+    t: i16,
+    next_bits: i16,
+}
+
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
+impl BitSequence {
+    pub const fn new(bits: i16) -> Self {
+        Self {
+            bits,
+            t: 0,
+            next_bits: 0,
+        }
+    }
+
+    pub fn get_next(&mut self) -> i16 {
+        self.t = self.bits | self.bits.wrapping_sub(1);
+        self.next_bits = self.t.wrapping_add(1);
+        self.next_bits |= ((!self.t & (!self.t).wrapping_neg()).wrapping_sub(1))
+            >> self.bits.trailing_zeros().wrapping_add(1);
+        self.bits = self.next_bits;
+        self.next_bits
+    }
+}
+
+pub fn const_combos<T, const N: usize>(items: &[T]) -> impl Iterator<Item = [T; N]> + '_
+where
+    T: Copy,
+{
+    Combinations::new(items)
+}
+
+/// Find the combination at `index` among all combinations of `N` items from
+/// `items`, in the same order that [`const_combos`] yields them.
+pub fn nth_combination<T: Copy, const N: usize>(items: &[T], mut index: usize) -> [T; N] {
+    let mut start = 0;
+    array::from_fn(|slot| {
+        let remaining_slots = N - slot - 1;
+        let mut i = start;
+        loop {
+            // The number of combinations where `items[i]` fills this slot
+            let count = binomial(items.len() - i - 1, remaining_slots);
+            if index < count {
+                break;
+            }
+            index -= count;
+            i += 1;
+        }
+        start = i + 1;
+        items[i]
+    })
+}
+
+/// The inverse of [`nth_combination`]: find the index of `combo` among all
+/// combinations of `N` items from `items`. The elements of `combo` must appear
+/// in the same order as they do in `items`.
+pub fn combination_index<T: PartialEq, const N: usize>(items: &[T], combo: &[T; N]) -> usize {
+    let mut index = 0;
+    let mut start = 0;
+    for (slot, item) in combo.iter().enumerate() {
+        let remaining_slots = N - slot - 1;
+        let position = start
+            + items[start..]
+                .iter()
+                .position(|x| x == item)
+                .expect("item not found in combination source");
+        for i in start..position {
+            index += binomial(items.len() - i - 1, remaining_slots);
+        }
+        start = position + 1;
+    }
+    index
+}
+
+/// The binomial coefficient "n choose k".
+pub const fn binomial(n: usize, k: usize) -> usize {
+    if k > n {
+        return 0;
+    }
+    let mut result = 1;
+    let mut i = 0;
+    while i < k {
+        result = result * (n - i) / (i + 1);
+        i += 1;
+    }
+    result
+}
+
+/// The number of multisets of five ranks, including the impossible ones with
+/// five of one rank.
+pub const FIVE_RANK_MULTISETS: usize = binomial(13 + 5 - 1, 5);
+
+/// `FIVE_RANK_OFFSETS[i][rank]` is how much the `i`th lowest of five ranks adds
+/// to the index of their multiset, following the combinatorial number system:
+/// "`rank + i` choose `i + 1`".
+pub const FIVE_RANK_OFFSETS: [[u16; 13]; 5] = {
+    let mut offsets = [[0; 13]; 5];
+    let mut i = 0;
+    while i < 5 {
+        let mut rank = 0;
+        while rank < 13 {
+            offsets[i][rank] = binomial(rank + i, i + 1) as u16;
+            rank += 1;
+        }
+        i += 1;
+    }
+    offsets
+};
+
+/// The index of a multiset of five ranks, from zero up to
+/// [`FIVE_RANK_MULTISETS`], given the ranks from lowest to highest.
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
+pub fn five_rank_index(ranks: [usize; 5]) -> usize {
+    debug_assert!(ranks.windows(2).all(|pair| pair[0] <= pair[1]));
+    ranks
+        .into_iter()
+        .enumerate()
+        .map(|(i, rank)| usize::from(FIVE_RANK_OFFSETS[i][rank]))
+        .sum()
+}
+
+/// Collect every rank, highest first, skipping any in `excluded`. Returns the
+/// number of ranks written to `buf`.
+pub fn descending_ranks_except(excluded: &[Rank], buf: &mut [Rank; 13]) -> usize {
+    let mut len = 0;
+    for &rank in Rank::ALL_VARIANTS.iter().rev() {
+        if !excluded.contains(&rank) {
+            buf[len] = rank;
+            len += 1;
+        }
+    }
+    len
+}
+
+/// Calculate a hand's prime product by using it's bit rank representation.
+pub fn prime_product_from_rank_bits(rank_bits: i16) -> i32 {
+    let mut product: i32 = 1;
+    for i in INT_RANKS {
+        // Check to see if the bit for a given rank is turned on
+        if rank_bits & (1 << i) != 0 {
+            // If so, we multiply in the prime number corresponding to that rank
+            product = product.wrapping_mul(PRIMES[i as usize]);
+        }
+    }
+    product
+}
+
+/// Obtain the high card from a given set of rank bits bit-ORed together.
+pub fn high_rank_from_rank_bits(rank_bits: i16) -> Rank {
+    // We don't want to return an Ace as the high card if it's a five-high straight
+    if rank_bits == hand_rank::constants::STRAIGHTS[9] {
+        return Rank::Five;
+    }
+    for i in INT_RANKS.rev() {
+        if rank_bits & (1 << i) != 0 {
+            return Rank::ALL_VARIANTS[i as usize];
+        }
+    }
+    unreachable!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn five_rank_indices_are_dense() {
+        let mut seen = vec![false; FIVE_RANK_MULTISETS];
+        // Every multiset of five ranks, as a combination with repetition
+        for [a, b, c, d, e] in const_combos::<_, 5>(&(0..17).collect::<Vec<_>>()) {
+            let index = five_rank_index([a, b - 1, c - 2, d - 3, e - 4]);
+            assert!(!seen[index]);
+            seen[index] = true;
+        }
+        assert!(seen.into_iter().all(|seen| seen));
+    }
+
+    #[test]
+    fn bit_sequence_generator_works() {
+        let some_number = 0b10011;
+        let mut xs = BitSequence::new(some_number);
+
+        let mut next_check = move |bin: i16| {
+            assert_eq!(xs.get_next(), bin);
+        };
+
+        next_check(0b00010101);
+        next_check(0b00010110);
+        next_check(0b00011001);
+        next_check(0b00011010);
+        next_check(0b00011100);
+        next_check(0b00100011);
+    }
+
+    #[test]
+    fn nth_combination_matches_const_combos() {
+        let items = INT_RANKS.rev().collect::<Vec<_>>();
+        for (index, combo) in const_combos::<_, 3>(&items).enumerate() {
+            assert_eq!(nth_combination::<_, 3>(&items, index), combo);
+            assert_eq!(combination_index(&items, &combo), index);
+        }
+        assert_eq!(binomial(13, 5), 1287);
+    }
+
+    #[test]
+    fn const_combos_works() {
+        let combos = Combinations::<'_, _, 2>::new(&['c', 'a', 't']).collect::<Vec<_>>();
+        dbg!(&combos);
+        let expected_combos: [[char; 2]; 3] = [['c', 'a'], ['c', 't'], ['a', 't']];
+        assert_eq!(combos.len(), expected_combos.len());
+        for &combo in &expected_combos {
+            assert!(combos.contains(&combo));
+        }
+    }
+}
@@ -0,0 +1,391 @@
+use std::fmt;
+
+use crate::{
+    constants::PRIMES,
+    evaluate::{evaluation::Evaluation, utils},
+    Card, Eval, EvalClass, EvalError, Rank,
+};
+
+/// A card in a game played with wild cards. Besides the natural cards of a
+/// standard deck, a hand may hold any number of jokers, which are always wild.
+///
+/// Natural cards can be made wild by rank as well, like the deuces in Deuces
+/// Wild, by passing their ranks to [`Evaluator::evaluate_wild`].
+///
+/// [`Evaluator::evaluate_wild`]: crate::Evaluator::evaluate_wild
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum WildCard {
+    /// A natural card from a standard 52-card deck.
+    Natural(Card),
+    /// A joker, which can stand in for any card.
+    Joker,
+}
+
+impl From<Card> for WildCard {
+    fn from(card: Card) -> Self { Self::Natural(card) }
+}
+
+impl fmt::Display for WildCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Natural(card) => card.fmt(f),
+            Self::Joker => write!(f, "[ Joker ]"),
+        }
+    }
+}
+
+/// The result of evaluating a hand with wild cards, returned from
+/// [`Evaluator::evaluate_wild`]. Every wild card has been substituted with
+/// whichever card makes the best hand.
+///
+/// Besides every hand an [`Eval`] can describe, a `WildEval` may be five of a
+/// kind, which beats every other hand; see [`WildEvalClass`]. Like [`Eval`],
+/// `WildEval` implements [`Ord`] such that better hands are greater, and shows
+/// the name of the hand when printed in [`Display`](fmt::Display) format.
+///
+/// [`Evaluator::evaluate_wild`]: crate::Evaluator::evaluate_wild
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WildEval(WildHand);
+
+// Variants are declared from worst to best for the derived `Ord`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum WildHand {
+    Natural(Eval),
+    FiveOfAKind(Rank),
+}
+
+/// The class of a hand made with wild cards, as returned from
+/// [`WildEval::class`]. This is either one of the classes of an [`EvalClass`],
+/// or five of a kind, which beats all of them.
+///
+/// # Example
+///
+/// ```
+/// use poker::{EvalClass, Rank, WildEvalClass};
+///
+/// let royal = WildEvalClass::Natural(EvalClass::StraightFlush {
+///     high_rank: Rank::Ace,
+/// });
+/// let fives = WildEvalClass::FiveOfAKind { rank: Rank::Two };
+/// assert!(fives > royal);
+/// assert_eq!(fives.to_string(), "Five of a kind, twos");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WildEvalClass {
+    /// A hand that can also be made without wild cards.
+    Natural(EvalClass),
+    /// Five of a kind, five cards of the same rank, which can only be made with
+    /// wild cards.
+    FiveOfAKind {
+        /// The rank of the five of a kind.
+        rank: Rank,
+    },
+}
+
+impl From<EvalClass> for WildEvalClass {
+    fn from(class: EvalClass) -> Self { Self::Natural(class) }
+}
+
+impl fmt::Display for WildEvalClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Natural(class) => class.fmt(f),
+            Self::FiveOfAKind { rank } => {
+                write!(f, "Five of a kind, {}", rank.as_str_name_plural())
+            }
+        }
+    }
+}
+
+impl WildEval {
+    /// Obtain the class of this poker hand, as with [`Eval::class`], or five of
+    /// a kind.
+    pub const fn class(self) -> WildEvalClass {
+        match self.0 {
+            WildHand::Natural(eval) => WildEvalClass::Natural(eval.class()),
+            WildHand::FiveOfAKind(rank) => WildEvalClass::FiveOfAKind { rank },
+        }
+    }
+
+    /// Recover the ranks of all five cards in this hand, after wild cards have
+    /// been substituted, ordered by significance as with [`Eval::ranks`].
+    pub fn ranks(self) -> [Rank; 5] {
+        match self.0 {
+            WildHand::Natural(eval) => eval.ranks(),
+            WildHand::FiveOfAKind(rank) => [rank; 5],
+        }
+    }
+
+    /// The equivalent hand without wild cards, or `None` if this hand is five
+    /// of a kind, which can't be made without them.
+    pub const fn eval(self) -> Option<Eval> {
+        match self.0 {
+            WildHand::Natural(eval) => Some(eval),
+            WildHand::FiveOfAKind(_) => None,
+        }
+    }
+
+    /// Check whether this hand is better than another.
+    pub fn is_better_than(self, other: Self) -> bool { self > other }
+
+    /// Check whether this hand is worse than another.
+    pub fn is_worse_than(self, other: Self) -> bool { self < other }
+
+    /// Check whether this hand is five of a kind.
+    pub const fn is_five_of_a_kind(self) -> bool { matches!(self.0, WildHand::FiveOfAKind(_)) }
+}
+
+impl From<Eval> for WildEval {
+    fn from(eval: Eval) -> Self { Self(WildHand::Natural(eval)) }
+}
+
+impl fmt::Display for WildEval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            WildHand::Natural(eval) => eval.fmt(f),
+            WildHand::FiveOfAKind(rank) => WildEvalClass::FiveOfAKind { rank }.fmt(f),
+        }
+    }
+}
+
+/// Find the best hand among any five of the given cards, where jokers and
+/// natural cards of the `wild_ranks` are wild.
+pub fn evaluate_wild(
+    evaluator: &impl Evaluation,
+    cards: &[WildCard],
+    wild_ranks: &[Rank],
+) -> Result<WildEval, EvalError> {
+    let all_naturals = cards
+        .iter()
+        .filter_map(|card| match *card {
+            WildCard::Natural(card) => Some(card),
+            WildCard::Joker => None,
+        })
+        .collect::<Vec<_>>();
+    if !utils::all_unique(&all_naturals) {
+        return Err(EvalError::cards_not_unique(&all_naturals));
+    }
+    if cards.len() < 5 {
+        return Err(EvalError::InvalidHandSize(cards.len()));
+    }
+
+    let naturals = all_naturals
+        .into_iter()
+        .filter(|card| !wild_ranks.contains(&card.rank()))
+        .collect::<Vec<_>>();
+    // A wild card can always stand in for the natural card it replaces, so
+    // the best hand uses as many wild cards as it can
+    let wilds = (cards.len() - naturals.len()).min(5);
+    if wilds == 0 {
+        return Ok(super::evaluation::evaluate_unique(evaluator, &naturals)?.into());
+    }
+
+    let mut best = None;
+    for_each_subset(&naturals, 5 - wilds, &mut |subset| {
+        let hand = best_substitution(evaluator, subset, wilds);
+        if best.map_or(true, |best| hand > best) {
+            best = Some(hand);
+        }
+    });
+    Ok(best.expect("there is always at least one combination"))
+}
+
+/// Call `f` with every subset of `size` items, keeping their original order.
+fn for_each_subset(items: &[Card], size: usize, f: &mut impl FnMut(&[Card])) {
+    fn recurse(items: &[Card], size: usize, chosen: &mut Vec<Card>, f: &mut impl FnMut(&[Card])) {
+        if chosen.len() == size {
+            return f(chosen);
+        }
+        let needed = size - chosen.len();
+        for (i, &card) in items.iter().enumerate().take(items.len() + 1 - needed) {
+            chosen.push(card);
+            recurse(&items[i + 1..], size, chosen, f);
+            chosen.pop();
+        }
+    }
+    recurse(items, size, &mut Vec::with_capacity(size), f);
+}
+
+/// Find the best five-card hand made of the natural cards and `wilds` wild
+/// cards, which together total five.
+fn best_substitution(evaluator: &impl Evaluation, naturals: &[Card], wilds: usize) -> WildEval {
+    let mut natural_ranks = naturals.iter().map(|card| card.rank());
+    match natural_ranks.next() {
+        None => return WildEval(WildHand::FiveOfAKind(Rank::Ace)),
+        Some(rank) if natural_ranks.all(|other| other == rank) => {
+            return WildEval(WildHand::FiveOfAKind(rank));
+        }
+        Some(_) => {}
+    }
+
+    // Wild cards only need ranks: if every natural card shares a suit, the
+    // wild cards can share it too, and otherwise suits can't make a flush
+    let suited = naturals
+        .iter()
+        .all(|card| card.suit() == naturals[0].suit());
+    let natural_product = naturals
+        .iter()
+        .fold(1, |acc, card| acc * PRIMES[card.rank() as usize]);
+    let natural_bits = naturals
+        .iter()
+        .fold(0u16, |acc, card| acc | 1 << card.rank() as u8);
+
+    // Each multiset of ranks for the wild cards is tried once
+    let mut best = Eval::WORST;
+    let mut wild_ranks = vec![0; wilds];
+    loop {
+        let (product, bits) = wild_ranks
+            .iter()
+            .fold((natural_product, natural_bits), |(product, bits), &rank| {
+                (product * PRIMES[rank], bits | 1 << rank)
+            });
+        // With at least two different natural ranks, no rank can appear five
+        // times, so every product is in the tables
+        let eval = if suited && bits.count_ones() == 5 {
+            Eval(evaluator.flush_lookup()[&product])
+        } else {
+            Eval(evaluator.unsuited_lookup()[&product])
+        };
+        best = best.max(eval);
+
+        // Advance to the next non-decreasing sequence of rank indices
+        match wild_ranks.iter().rposition(|&rank| rank < 12) {
+            Some(i) => {
+                let next = wild_ranks[i] + 1;
+                wild_ranks[i..].iter_mut().for_each(|rank| *rank = next);
+            }
+            None => break,
+        }
+    }
+    best.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluate::tests::EVALUATOR;
+
+    fn parse(cards: &str) -> Vec<WildCard> {
+        cards
+            .split_whitespace()
+            .map(|card| match card {
+                "Jk" => WildCard::Joker,
+                card => WildCard::Natural(card.parse().unwrap()),
+            })
+            .collect()
+    }
+
+    fn evaluate(cards: &str, wild_ranks: &[Rank]) -> WildEval {
+        EVALUATOR.evaluate_wild(parse(cards), wild_ranks).unwrap()
+    }
+
+    #[test]
+    fn jokers_make_the_best_hand() {
+        let cases = [
+            ("Ac Ad Ah As Jk", "Five of a kind, aces"),
+            ("Jk Jk Jk Jk Jk", "Five of a kind, aces"),
+            ("7c 7d Jk Jk 2s", "Four of a kind, sevens"),
+            ("Tc Jc Qc Kc Jk", "Royal flush"),
+            ("Tc Jc Qc Kd Jk", "Straight, ace-high"),
+            ("2c 7c 9c Kc Jk", "Flush, ace-high"),
+            ("2c 3d 4h 6s Jk", "Straight, six-high"),
+            ("Kc Kd 9h 9s Jk", "Full house, kings over nines"),
+            ("Ac Kd 8h 6s Jk", "Pair, aces"),
+        ];
+        for &(hand, name) in &cases {
+            assert_eq!(evaluate(hand, &[]).to_string(), name, "{}", hand);
+        }
+    }
+
+    #[test]
+    fn wild_ranks() {
+        // Deuces wild
+        let result = evaluate("2c 2d 9h 9s 5c", &[Rank::Two]);
+        assert_eq!(
+            result.class(),
+            WildEvalClass::Natural(EvalClass::FourOfAKind { quads: Rank::Nine })
+        );
+        let result = evaluate("2c 2d 2h 2s Kc", &[Rank::Two]);
+        assert_eq!(
+            result.class(),
+            WildEvalClass::FiveOfAKind { rank: Rank::King }
+        );
+        // Deuces are natural when they aren't wild
+        let result = evaluate("2c 2d 9h 9s 5c", &[]);
+        assert!(
+            result.class()
+                == WildEvalClass::Natural(EvalClass::TwoPair {
+                    first_pair: Rank::Nine,
+                    second_pair: Rank::Two
+                })
+        );
+        // Wild ranks and jokers together
+        let result = evaluate("2c Jk 3h 3s 3c Kd 9d", &[Rank::Two]);
+        assert_eq!(
+            result.class(),
+            WildEvalClass::FiveOfAKind { rank: Rank::Three }
+        );
+    }
+
+    #[test]
+    fn without_wilds_matches_evaluator() {
+        let hand = "Ac Kd 9h 9s 4c 3d 2h";
+        let cards = Card::parse_to_iter(hand.split_whitespace())
+            .try_collect::<Vec<_>>()
+            .unwrap();
+        let result = evaluate(hand, &[]);
+        assert_eq!(result.eval(), Some(EVALUATOR.evaluate(cards).unwrap()));
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        // Substituting every possible card for a single joker, while keeping
+        // the natural cards unique, can only be beaten by five of a kind
+        let hands = ["As Ks 4s 7s", "9c 9d 4h 4s", "5c 6d 8h 9s", "Qc Qd Qh 2s"];
+        for hand in &hands {
+            let naturals = Card::parse_to_iter(hand.split_whitespace())
+                .try_collect::<Vec<_>>()
+                .unwrap();
+            let brute_force = crate::deck::generate()
+                .filter(|card| !naturals.contains(card))
+                .map(|card| {
+                    let mut cards = naturals.clone();
+                    cards.push(card);
+                    EVALUATOR.evaluate(cards).unwrap()
+                })
+                .max();
+            let mut cards = parse(hand);
+            cards.push(WildCard::Joker);
+            assert_eq!(
+                EVALUATOR.evaluate_wild(cards, &[]).unwrap().eval(),
+                brute_force
+            );
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        let five = evaluate("Ac Ad Ah As Jk", &[]);
+        let fives = evaluate("5c 5d 5h Jk Jk", &[]);
+        let royal = evaluate("Tc Jc Qc Kc Jk", &[]);
+        assert!(five.is_better_than(fives));
+        assert!(fives.is_better_than(royal));
+        assert!(royal.is_worse_than(five));
+        assert!(fives.is_five_of_a_kind() && !royal.is_five_of_a_kind());
+        assert_eq!(fives.ranks(), [Rank::Five; 5]);
+        assert_eq!(fives.eval(), None);
+        assert!(WildEvalClass::FiveOfAKind { rank: Rank::Two } > royal.class());
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        assert_eq!(
+            EVALUATOR.evaluate_wild(parse("Ac Kd Jk Jk"), &[]),
+            Err(EvalError::InvalidHandSize(4))
+        );
+        assert!(matches!(
+            EVALUATOR.evaluate_wild(parse("Ac Ac Jk Jk 5d"), &[]),
+            Err(EvalError::CardsNotUnique(_))
+        ));
+    }
+}
@@ -0,0 +1,327 @@
+//! Reduce hands and boards to a canonical form by renaming their suits.
+//!
+//! Suits have no ranking in Texas Holdem, so two situations that differ only
+//! by a renaming of the suits are strategically identical: holding `AhKh` on a
+//! `Qh 7c 2d` flop is no different from holding `AsKs` on `Qs 7d 2c`. Such
+//! situations are called *isomorphic*, and solvers and lookup tables only need
+//! to consider one representative of each isomorphism class.
+//!
+//! [`canonicalize`] renames the suits of some groups of cards, such as hole
+//! cards and a board, so that every isomorphic input produces the same output.
+//! [`canonical_flops`] and [`canonical_turns`] enumerate the isomorphism
+//! classes of flops and turns, along with how many concrete boards belong to
+//! each.
+//!
+//! ```
+//! use poker::{cards, isomorphism, Card};
+//!
+//! let hand = cards!("Ah Kh").try_collect::<Vec<_>>().unwrap();
+//! let board = cards!("Qh 7c 2d").try_collect::<Vec<_>>().unwrap();
+//! let other_hand = cards!("As Ks").try_collect::<Vec<_>>().unwrap();
+//! let other_board = cards!("Qs 7d 2c").try_collect::<Vec<_>>().unwrap();
+//!
+//! let (canonical, permutation) = isomorphism::canonicalize(&[&hand, &board]);
+//! let (other_canonical, _) = isomorphism::canonicalize(&[&other_hand, &other_board]);
+//! assert_eq!(canonical, other_canonical);
+//! assert_eq!(permutation.apply_card(hand[0]), canonical[0][0]);
+//!
+//! assert_eq!(isomorphism::canonical_flops().len(), 1755);
+//! ```
+
+use std::{cmp::Reverse, collections::BTreeMap};
+
+use crate::{evaluate::utils, Card, Suit};
+
+/// The suits that canonical forms use, in the order they are assigned.
+const CANONICAL_ORDER: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+
+/// A one-to-one renaming of the four suits.
+///
+/// A permutation is returned by [`canonicalize`] to describe how the suits of
+/// its input were renamed, and its [`inverse`](SuitPermutation::inverse) maps
+/// canonical cards back to the original suits.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SuitPermutation {
+    /// The suit that each suit is renamed to, indexed by the original suit.
+    suits: [Suit; 4],
+}
+
+impl SuitPermutation {
+    /// The permutation that leaves every suit unchanged.
+    pub const IDENTITY: Self = Self {
+        suits: [Suit::Clubs, Suit::Hearts, Suit::Spades, Suit::Diamonds],
+    };
+
+    /// Iterate over all 24 permutations of the four suits, starting with
+    /// [`IDENTITY`](Self::IDENTITY).
+    pub fn all() -> impl Iterator<Item = Self> {
+        let variants = Suit::ALL_VARIANTS;
+        (0..4)
+            .flat_map(|a| (0..4).map(move |b| (a, b)))
+            .flat_map(|(a, b)| (0..4).map(move |c| (a, b, c)))
+            .filter(|&(a, b, c)| a != b && a != c && b != c)
+            .map(move |(a, b, c)| Self {
+                suits: [
+                    variants[a],
+                    variants[b],
+                    variants[c],
+                    variants[6 - a - b - c],
+                ],
+            })
+    }
+
+    /// The suit that `suit` is renamed to.
+    pub const fn apply(self, suit: Suit) -> Suit { self.suits[suit as usize] }
+
+    /// The card with the same rank as `card`, and its suit renamed.
+    pub const fn apply_card(self, card: Card) -> Card {
+        Card::new(card.rank(), self.apply(card.suit()))
+    }
+
+    /// The permutation that undoes this one.
+    pub fn inverse(self) -> Self {
+        let mut suits = Self::IDENTITY.suits;
+        for &suit in Suit::ALL_VARIANTS {
+            suits[self.apply(suit) as usize] = suit;
+        }
+        Self { suits }
+    }
+}
+
+impl Default for SuitPermutation {
+    fn default() -> Self { Self::IDENTITY }
+}
+
+/// Rename the suits of some groups of cards to their canonical form, and
+/// return the renamed groups along with the permutation that was applied.
+///
+/// Each group, such as a player's hole cards or the cards of one street, is
+/// treated as unordered, but the groups themselves are not interchangeable, so
+/// a card on the flop is distinguished from the same card on the turn. Two
+/// inputs produce the same output exactly when one can be turned into the
+/// other by renaming suits and reordering cards within groups.
+///
+/// Suits are assigned in the order spades, hearts, diamonds, then clubs: the
+/// suit holding the highest ranks in the first group becomes spades, ties are
+/// broken by the following groups, and so on. The cards of each returned group
+/// are sorted from highest to lowest rank, and then by suit in the same order.
+/// The cards are expected to be unique.
+///
+/// # Example
+///
+/// ```
+/// use poker::{cards, isomorphism, Card};
+///
+/// let hand = cards!("Td 9c").try_collect::<Vec<_>>().unwrap();
+/// let board = cards!("8c 2d 2h").try_collect::<Vec<_>>().unwrap();
+/// let (canonical, permutation) = isomorphism::canonicalize(&[&hand, &board]);
+///
+/// let expected_hand = cards!("Ts 9h").try_collect::<Vec<_>>().unwrap();
+/// let expected_board = cards!("8h 2s 2d").try_collect::<Vec<_>>().unwrap();
+/// assert_eq!(canonical, [expected_hand, expected_board]);
+///
+/// let original: Vec<Card> = canonical[0]
+///     .iter()
+///     .map(|&card| permutation.inverse().apply_card(card))
+///     .collect();
+/// assert_eq!(original, hand);
+/// ```
+pub fn canonicalize(groups: &[&[Card]]) -> (Vec<Vec<Card>>, SuitPermutation) {
+    let permutation = canonical_permutation(groups);
+    let canonical = groups
+        .iter()
+        .map(|group| {
+            let mut group: Vec<Card> = group
+                .iter()
+                .map(|&card| permutation.apply_card(card))
+                .collect();
+            group.sort_unstable_by_key(|card| {
+                let suit = CANONICAL_ORDER.iter().position(|&suit| suit == card.suit());
+                (Reverse(card.rank()), suit)
+            });
+            group
+        })
+        .collect();
+    (canonical, permutation)
+}
+
+/// Find the permutation that [`canonicalize`] would apply to `groups`.
+fn canonical_permutation(groups: &[&[Card]]) -> SuitPermutation {
+    // The ranks held by each suit within each group, as bits
+    let masks: Vec<[u16; 4]> = groups
+        .iter()
+        .map(|group| {
+            let mut masks = [0; 4];
+            for card in group.iter() {
+                masks[card.suit() as usize] |= 1 << card.rank() as u16;
+            }
+            masks
+        })
+        .collect();
+
+    // A stable sort keeps tied suits in a fixed order. Tied suits hold exactly
+    // the same ranks in every group, so the order between them doesn't change
+    // the canonical cards
+    let mut order = [0, 1, 2, 3];
+    order.sort_by_key(|&suit| Reverse(masks.iter().map(|m| m[suit]).collect::<Vec<_>>()));
+
+    let mut suits = SuitPermutation::IDENTITY.suits;
+    for (&suit, &canonical) in order.iter().zip(CANONICAL_ORDER.iter()) {
+        suits[suit] = canonical;
+    }
+    SuitPermutation { suits }
+}
+
+/// Enumerate the isomorphism classes of flops.
+///
+/// Each class is given by its canonical flop, as produced by
+/// [`canonicalize`], and the number of the 22,100 concrete flops that belong
+/// to it. There are 1,755 classes, returned in a fixed order.
+pub fn canonical_flops() -> Vec<([Card; 3], usize)> {
+    let deck: Vec<Card> = crate::deck::generate().collect();
+    let mut classes = BTreeMap::new();
+    for flop in utils::const_combos::<_, 3>(&deck) {
+        let (canonical, _) = canonicalize(&[&flop]);
+        *classes.entry(canonical_key::<3>(&canonical)).or_insert(0) += 1;
+    }
+    classes
+        .into_iter()
+        .map(|(key, count)| (key.map(Card::from_index), count))
+        .collect()
+}
+
+/// Enumerate the isomorphism classes of turns, where the flop and the turn
+/// card are kept apart.
+///
+/// Each class is given by its canonical flop and turn card, as produced by
+/// passing them to [`canonicalize`] as separate groups, and the number of the
+/// 1,082,900 concrete flop and turn pairs that belong to it. There are 63,193
+/// classes, returned in a fixed order.
+pub fn canonical_turns() -> Vec<([Card; 3], Card, usize)> {
+    let deck: Vec<Card> = crate::deck::generate().collect();
+    let mut classes = BTreeMap::new();
+    // Every concrete flop is a renaming of its canonical flop, and renaming the
+    // turn card along with it gives an isomorphic turn, so it's enough to
+    // extend each canonical flop and weight the results by its class size
+    for (flop, flop_count) in canonical_flops() {
+        for &turn in deck.iter().filter(|card| !flop.contains(card)) {
+            let (canonical, _) = canonicalize(&[&flop, &[turn]]);
+            *classes.entry(canonical_key::<4>(&canonical)).or_insert(0) += flop_count;
+        }
+    }
+    classes
+        .into_iter()
+        .map(|(key, count)| {
+            let [a, b, c, turn] = key.map(Card::from_index);
+            ([a, b, c], turn, count)
+        })
+        .collect()
+}
+
+/// The indices of canonical board cards, used to order the classes.
+fn canonical_key<const N: usize>(groups: &[Vec<Card>]) -> [u8; N] {
+    let mut key = [0; N];
+    for (slot, card) in key.iter_mut().zip(groups.iter().flatten()) {
+        *slot = card.index();
+    }
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::cards;
+
+    fn cards(s: &str) -> Vec<Card> { cards!(s).try_collect().expect("couldn't parse cards") }
+
+    #[test]
+    fn suit_permutations_are_unique() {
+        let permutations: Vec<SuitPermutation> = SuitPermutation::all().collect();
+        assert_eq!(permutations.len(), 24);
+        assert_eq!(permutations[0], SuitPermutation::IDENTITY);
+        assert_eq!(permutations.iter().collect::<HashSet<_>>().len(), 24);
+        for permutation in permutations {
+            let images: HashSet<Suit> = Suit::ALL_VARIANTS
+                .iter()
+                .map(|&suit| permutation.apply(suit))
+                .collect();
+            assert_eq!(images.len(), 4);
+            assert_eq!(permutation.inverse().inverse(), permutation);
+            for &suit in Suit::ALL_VARIANTS {
+                assert_eq!(permutation.inverse().apply(permutation.apply(suit)), suit);
+            }
+        }
+    }
+
+    #[test]
+    fn isomorphic_inputs_share_a_canonical_form() {
+        let hand = cards("Ah Kh");
+        let board = cards("Qh 7c 2d");
+        let (canonical, permutation) = canonicalize(&[&hand, &board]);
+        assert_eq!(canonical, [cards("As Ks"), cards("Qs 7h 2d")]);
+        for (group, canonical_group) in [&hand, &board].iter().zip(canonical.iter()) {
+            for card in group.iter() {
+                assert!(canonical_group.contains(&permutation.apply_card(*card)));
+            }
+        }
+
+        for renaming in SuitPermutation::all() {
+            let rename = |cards: &[Card]| -> Vec<Card> {
+                cards
+                    .iter()
+                    .rev()
+                    .map(|&card| renaming.apply_card(card))
+                    .collect()
+            };
+            let (other, _) = canonicalize(&[&rename(&hand), &rename(&board)]);
+            assert_eq!(other, canonical);
+        }
+    }
+
+    #[test]
+    fn groups_are_not_interchangeable() {
+        let (flop_first, _) = canonicalize(&[&cards("Ah Kh 2c"), &cards("3c")]);
+        let (turn_first, _) = canonicalize(&[&cards("Ah Kh 3c"), &cards("2c")]);
+        assert_ne!(flop_first, turn_first);
+
+        let (hand_flush, _) = canonicalize(&[&cards("Ah Kh"), &cards("Qc Jc 2d")]);
+        let (board_flush, _) = canonicalize(&[&cards("Ah Kc"), &cards("Qh Jc 2d")]);
+        assert_ne!(hand_flush, board_flush);
+    }
+
+    #[test]
+    fn flop_classes() {
+        let flops = canonical_flops();
+        assert_eq!(flops.len(), 1755);
+        assert_eq!(flops.iter().map(|&(_, count)| count).sum::<usize>(), 22100);
+        for &(flop, _) in flops.iter() {
+            assert_eq!(canonicalize(&[&flop]).0, [flop.to_vec()]);
+        }
+        let rainbow = flops
+            .iter()
+            .find(|(flop, _)| flop[..] == cards("As Kh Qd")[..]);
+        assert_eq!(rainbow.map(|&(_, count)| count), Some(24));
+        let monotone = flops
+            .iter()
+            .find(|(flop, _)| flop[..] == cards("As Ks Qs")[..]);
+        assert_eq!(monotone.map(|&(_, count)| count), Some(4));
+    }
+
+    #[test]
+    fn turn_classes() {
+        let turns = canonical_turns();
+        assert_eq!(turns.len(), 63193);
+        assert_eq!(
+            turns.iter().map(|&(.., count)| count).sum::<usize>(),
+            22100 * 49
+        );
+        for &(flop, turn, _) in turns.iter().step_by(97) {
+            assert_eq!(
+                canonicalize(&[&flop, &[turn]]).0,
+                [flop.to_vec(), vec![turn]]
+            );
+        }
+    }
+}
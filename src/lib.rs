@@ -44,9 +44,13 @@
 //! multiple threads.
 //!
 //! ```
+//! # #[cfg(feature = "rand")]
 //! # fn main() {
 //! #     if run().is_err() { std::process::exit(1); }
 //! # }
+//! # #[cfg(not(feature = "rand"))]
+//! # fn main() {}
+//! # #[cfg(feature = "rand")]
 //! # fn run() -> Result<(), Box<dyn std::error::Error>> {
 //! use std::{cmp, sync::Arc, thread};
 //!
@@ -72,24 +76,100 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! With the `rayon` feature, [`Evaluator::par_evaluate_many`],
+//! [`equity::par_exact`], and [`equity::par_exact_ranges`] do the splitting for
+//! you, on [rayon]'s thread pool. Their results are the same no matter how many
+//! threads run.
+//!
+//! # Serialization
+//!
+//! With the `serde` feature, [`Card`], [`Rank`], [`Suit`], [`CardSet`],
+//! [`Eval`], [`EvalClass`], and the error types implement `Serialize` and
+//! `Deserialize`. In human-readable formats like JSON, a card is its
+//! two-character string, like `"As"`, and in compact formats like bincode, it
+//! is its [`Card::index`]. An [`Eval`] is always its
+//! [`strength`](Eval::strength), which stays the same across versions of this
+//! crate. To use the compact form of a card in JSON too, see
+//! [`serde_compact`].
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # fn main() {
+//! use poker::{cards, Card, Evaluator};
+//!
+//! let hand = cards!(Ace of Spades, Ten of Hearts);
+//! let json = serde_json::to_string(&hand).expect("couldn't serialize cards");
+//! assert_eq!(json, r#"["As","Th"]"#);
+//! assert_eq!(serde_json::from_str::<[Card; 2]>(&json).expect("couldn't parse cards"), hand);
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
+//! ```
+//!
+//! # `no_std` support
+//!
+//! Everything that needs the heap or the standard library is behind the
+//! default `std` feature. Without it, this crate is `#![no_std]` and doesn't
+//! allocate at all, but [`Card`], [`Rank`], [`Suit`], [`CardSet`], [`Eval`]
+//! and [`EvalClass`] are all still available, and hands can be evaluated with
+//! the static lookup table of the `static_lookup` feature:
+//!
+//! ```toml
+//! [dependencies]
+//! poker = { version = "0.7", default-features = false, features = ["static_lookup"] }
+//! ```
+//!
 //! [`treys`]: https://github.com/ihendley/treys
 
+#![cfg_attr(not(feature = "std"), no_std)]
+// Without `std` or `static_lookup`, there is no way to evaluate hands, and most
+// of the evaluation internals go unused
+#![cfg_attr(not(any(feature = "std", feature = "static_lookup")), allow(dead_code))]
 #![forbid(unsafe_code)]
 #![warn(missing_docs, missing_debug_implementations)]
-#![doc(html_root_url = "https://docs.rs/poker/0.6")]
+#![doc(html_root_url = "https://docs.rs/poker/0.7")]
 
-#[cfg(doctest)]
+// The README's examples shuffle decks
+#[cfg(all(doctest, feature = "rand"))]
 doc_comment::doctest!("../README.md");
 
 pub mod card;
 mod constants;
 pub mod deck;
+#[cfg(feature = "std")]
+pub mod equity;
 pub mod error;
 pub mod evaluate;
+#[cfg(feature = "std")]
+pub mod isomorphism;
+#[cfg(feature = "std")]
+pub mod range;
+#[cfg(feature = "serde")]
+pub mod serde_compact;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "std")]
+pub mod starting_hand;
 
 #[doc(inline)]
-pub use card::{Card, Rank, Suit};
+pub use card::{Card, CardNotation, CardSet, Duplicates, Rank, Suit};
 #[doc(inline)]
 pub use error::{EvalError, ParseCardError};
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use error::{ParseRangeError, ParseStartingHandError};
+#[doc(inline)]
+pub use evaluate::{AceFiveLow, DeuceSevenLow, Eval, EvalClass};
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use evaluate::{
+    Evaluator, HiLo, SevenCardEvaluator, ShortDeckEval, ShortDeckEvaluator, ShortDeckRules,
+    WildCard, WildEval, WildEvalClass,
+};
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use range::Range;
+#[cfg(feature = "std")]
 #[doc(inline)]
-pub use evaluate::{Eval, EvalClass, Evaluator};
+pub use starting_hand::StartingHand;
@@ -0,0 +1,671 @@
+//! Hold'em hand ranges, written in the shorthand poker players use to describe
+//! the hands an opponent might hold.
+//!
+//! A [`Range`] is a set of two-card combos, each with a weight between 0 and 1
+//! describing how often the combo is played. Ranges are usually parsed from
+//! strings such as `"QQ+, AKs, A5s-A2s, KJo, 76s:0.5, AhKh"`, and are printed
+//! back in the same compact notation.
+
+use std::{convert::TryFrom, fmt, str::FromStr};
+
+use crate::{error::ParseRangeError, Card, CardSet, Rank, Suit};
+
+/// The number of distinct two-card combos in a standard 52-card deck.
+pub(crate) const COMBOS: usize = 1326;
+
+/// A weighted set of two-card Texas Hold'em combos.
+///
+/// # Notation
+///
+/// A range is written as a comma-separated list of parts, each of which
+/// describes one or more combos:
+///
+/// - `QQ` is a pocket pair, and `QQ+` is that pair and every higher pair.
+/// - `AKs` is a suited hand, `AKo` is an offsuit hand, and `AK` is both.
+/// - `ATs+` is that hand and every hand with the same high card and a higher
+///   kicker, so `ATs`, `AJs`, `AQs` and `AKs`.
+/// - `QQ-99` and `A5s-A2s` are spans of pairs, or of hands sharing a high card.
+/// - `AhKh` is a single, specific combo.
+///
+/// Any part can be followed by a colon and a weight greater than 0 and at most
+/// 1, such as `76s:0.5`. Parts without a weight have a weight of 1. If a combo
+/// is described by more than one part, the last part wins.
+///
+/// Printing a `Range` with [`Display`](fmt::Display) produces a canonical,
+/// compact version of this notation which can be parsed back into an identical
+/// range.
+///
+/// # Example
+///
+/// ```
+/// use poker::{cards, Range};
+///
+/// let mut range: Range = "QQ+, AKs, A5s-A2s, 76s:0.5, AhKh"
+///     .parse()
+///     .expect("couldn't parse range");
+/// assert_eq!(range.combo_count(), 18 + 4 + 16 + 4);
+/// assert_eq!(range.weighted_combo_count(), 18.0 + 4.0 + 16.0 + 2.0);
+/// // "AhKh" is already part of "AKs"
+/// assert_eq!(range.to_string(), "QQ+, AKs, A5s-A2s, 76s:0.5");
+///
+/// // Remove every combo containing a card on the board
+/// range.remove_dead(cards!(King of Spades, Seven of Spades, Two of Diamonds));
+/// assert_eq!(range.combo_count(), 15 + 3 + 15 + 3);
+/// assert!(!range.contains(cards!(Ace of Spades, King of Spades)));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Range {
+    /// The weight of every combo, indexed by [`combo_index`].
+    weights: Vec<f64>,
+}
+
+impl Range {
+    /// Create a new, empty [`Range`].
+    pub fn new() -> Self {
+        Self {
+            weights: vec![0.0; COMBOS],
+        }
+    }
+
+    /// The weight of the given combo, or 0 if the combo is not in this range.
+    /// The order of the two cards does not matter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poker::{cards, Range};
+    ///
+    /// let range: Range = "AK, AKs:0.25".parse().expect("couldn't parse range");
+    /// assert_eq!(range.weight(cards!(Ace of Spades, King of Spades)), 0.25);
+    /// assert_eq!(range.weight(cards!(King of Hearts, Ace of Spades)), 1.0);
+    /// assert_eq!(range.weight(cards!(Ace of Spades, Queen of Spades)), 0.0);
+    /// ```
+    pub fn weight(&self, combo: [Card; 2]) -> f64 {
+        combo_index(combo).map_or(0.0, |index| self.weights[index])
+    }
+
+    /// Check whether the given combo is in this range with a nonzero weight.
+    pub fn contains(&self, combo: [Card; 2]) -> bool { self.weight(combo) > 0.0 }
+
+    /// Add a combo to this range with the given weight, replacing any previous
+    /// weight. The weight is clamped to between 0 and 1, and a weight of 0
+    /// removes the combo.
+    ///
+    /// Returns `false`, leaving the range unchanged, if both cards are the
+    /// same.
+    pub fn insert(&mut self, combo: [Card; 2], weight: f64) -> bool {
+        match combo_index(combo) {
+            Some(index) => {
+                self.weights[index] = if weight.is_nan() {
+                    0.0
+                } else {
+                    weight.clamp(0.0, 1.0)
+                };
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove a combo from this range, returning `true` if it was present.
+    pub fn remove(&mut self, combo: [Card; 2]) -> bool {
+        match combo_index(combo) {
+            Some(index) => {
+                let present = self.weights[index] > 0.0;
+                self.weights[index] = 0.0;
+                present
+            }
+            None => false,
+        }
+    }
+
+    /// Remove every combo that contains at least one of the given dead cards,
+    /// such as cards on the board or in another player's hand.
+    pub fn remove_dead(&mut self, dead: impl Into<CardSet>) {
+        let dead = dead.into();
+        for (index, (combo, _)) in all_combos().enumerate() {
+            if dead.contains(combo[0]) || dead.contains(combo[1]) {
+                self.weights[index] = 0.0;
+            }
+        }
+    }
+
+    /// The number of combos in this range, regardless of their weights.
+    pub fn combo_count(&self) -> usize { self.weights.iter().filter(|&&w| w > 0.0).count() }
+
+    /// The sum of the weights of every combo in this range. For a range where
+    /// every combo has a weight of 1, this is the same as
+    /// [`combo_count`](Range::combo_count).
+    pub fn weighted_combo_count(&self) -> f64 { self.weights.iter().sum() }
+
+    /// Check whether this range has no combos.
+    pub fn is_empty(&self) -> bool { self.weights.iter().all(|&w| w == 0.0) }
+
+    /// An iterator over the combos in this range and their weights. Each combo
+    /// has its higher card first, and combos are yielded in order of their
+    /// higher card, then of their lower card, according to [`Card::index`].
+    pub fn combos(&self) -> impl Iterator<Item = ([Card; 2], f64)> + '_ {
+        all_combos()
+            .map(move |(combo, index)| (combo, self.weights[index]))
+            .filter(|&(_, weight)| weight > 0.0)
+    }
+
+    /// The weight shared by every combo at the given indices, or `None` if
+    /// any of them is missing or their weights differ.
+    fn uniform_weight(&self, indices: &[usize]) -> Option<f64> {
+        let weight = self.weights[indices[0]];
+        if weight > 0.0 && indices.iter().all(|&index| self.weights[index] == weight) {
+            Some(weight)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for Range {
+    fn default() -> Self { Self::new() }
+}
+
+impl fmt::Debug for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Range").field(&self.to_string()).finish()
+    }
+}
+
+impl FromStr for Range {
+    type Err = ParseRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut range = Self::new();
+        for part in s.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+            let (hand, weight) = match part.split_once(':') {
+                Some((hand, weight)) => (hand.trim(), parse_weight(s, weight.trim())?),
+                None => (part, 1.0),
+            };
+            let indices = parse_hand(hand).ok_or_else(|| ParseRangeError::InvalidHand {
+                original_input: s.to_string(),
+                hand: hand.to_string(),
+            })?;
+            for index in indices {
+                range.weights[index] = weight;
+            }
+        }
+        Ok(range)
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut covered = vec![false; COMBOS];
+        let mut parts = Vec::new();
+
+        // Pairs, from aces down to deuces
+        let pairs = Rank::ALL_VARIANTS
+            .iter()
+            .rev()
+            .map(|&rank| self.full_class(Class::Pair(rank), &mut covered))
+            .collect::<Vec<_>>();
+        for (start, end, weight) in runs(&pairs) {
+            let high = Rank::ALL_VARIANTS[12 - start];
+            let low = Rank::ALL_VARIANTS[12 - end];
+            parts.push(format_run(
+                format!("{}{}", high, high),
+                format!("{}{}", low, low),
+                start == 0,
+                end - start,
+                weight,
+            ));
+        }
+
+        // Unpaired hands, grouped by high card, with each kicker from just
+        // below the high card down to deuces
+        for &high in Rank::ALL_VARIANTS[1..].iter().rev() {
+            let kickers = &Rank::ALL_VARIANTS[..high as usize];
+            let mut both = Vec::with_capacity(kickers.len());
+            let mut suited = Vec::with_capacity(kickers.len());
+            let mut offsuit = Vec::with_capacity(kickers.len());
+            for &low in kickers.iter().rev() {
+                let class = |suitedness| Class::Unpaired {
+                    high,
+                    low,
+                    suitedness,
+                };
+                let s = self.uniform_weight(&class(Suitedness::Suited).indices());
+                let o = self.uniform_weight(&class(Suitedness::Offsuit).indices());
+                if s.is_some() && s == o {
+                    both.push(s);
+                    suited.push(None);
+                    offsuit.push(None);
+                } else {
+                    both.push(None);
+                    suited.push(s);
+                    offsuit.push(o);
+                }
+            }
+            for (weights, suitedness) in [
+                (both, Suitedness::Any),
+                (suited, Suitedness::Suited),
+                (offsuit, Suitedness::Offsuit),
+            ] {
+                for (start, end, weight) in runs(&weights) {
+                    for offset in start..=end {
+                        let low = kickers[kickers.len() - 1 - offset];
+                        self.full_class(
+                            Class::Unpaired {
+                                high,
+                                low,
+                                suitedness,
+                            },
+                            &mut covered,
+                        );
+                    }
+                    let first = kickers[kickers.len() - 1 - start];
+                    let last = kickers[kickers.len() - 1 - end];
+                    let suffix = suitedness.suffix();
+                    parts.push(format_run(
+                        format!("{}{}{}", high, first, suffix),
+                        format!("{}{}{}", high, last, suffix),
+                        start == 0,
+                        end - start,
+                        weight,
+                    ));
+                }
+            }
+        }
+
+        // Whatever remains is written out combo by combo, highest first
+        let remaining = all_combos().collect::<Vec<_>>();
+        for &(combo, index) in remaining.iter().rev() {
+            let weight = self.weights[index];
+            if weight > 0.0 && !covered[index] {
+                parts.push(format!(
+                    "{}{}{}",
+                    combo[0].rank_suit_string(),
+                    combo[1].rank_suit_string(),
+                    format_weight(weight)
+                ));
+            }
+        }
+
+        f.write_str(&parts.join(", "))
+    }
+}
+
+impl Range {
+    /// The weight shared by every combo in the given class, marking those
+    /// combos as covered if there is one.
+    fn full_class(&self, class: Class, covered: &mut [bool]) -> Option<f64> {
+        let indices = class.indices();
+        let weight = self.uniform_weight(&indices);
+        if weight.is_some() {
+            for index in indices {
+                covered[index] = true;
+            }
+        }
+        weight
+    }
+}
+
+/// Which combos of an unpaired hand are included.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Suitedness {
+    Suited,
+    Offsuit,
+    Any,
+}
+
+impl Suitedness {
+    const fn suffix(self) -> &'static str {
+        match self {
+            Self::Suited => "s",
+            Self::Offsuit => "o",
+            Self::Any => "",
+        }
+    }
+}
+
+/// A group of combos described by ranks alone, such as "QQ" or "AKs".
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Class {
+    Pair(Rank),
+    Unpaired {
+        high: Rank,
+        low: Rank,
+        suitedness: Suitedness,
+    },
+}
+
+impl Class {
+    /// Parse a class such as "QQ", "AK", or "AKs". The ranks may be given in
+    /// either order.
+    fn parse(s: &str) -> Option<Self> {
+        let mut chars = s.chars();
+        let first = Rank::try_from(chars.next()?).ok()?;
+        let second = Rank::try_from(chars.next()?).ok()?;
+        let suitedness = match chars.next() {
+            None => Suitedness::Any,
+            Some('s') => Suitedness::Suited,
+            Some('o') => Suitedness::Offsuit,
+            Some(_) => return None,
+        };
+        if chars.next().is_some() {
+            return None;
+        }
+        match (first == second, suitedness) {
+            (true, Suitedness::Any) => Some(Self::Pair(first)),
+            (true, _) => None,
+            (false, _) => Some(Self::Unpaired {
+                high: first.max(second),
+                low: first.min(second),
+                suitedness,
+            }),
+        }
+    }
+
+    /// The combo indices of every combo in this class.
+    fn indices(self) -> Vec<usize> {
+        let mut indices = Vec::with_capacity(16);
+        let (high, low) = match self {
+            Self::Pair(rank) => (rank, rank),
+            Self::Unpaired { high, low, .. } => (high, low),
+        };
+        for (i, &high_suit) in Suit::ALL_VARIANTS.iter().enumerate() {
+            for (j, &low_suit) in Suit::ALL_VARIANTS.iter().enumerate() {
+                let included = match self {
+                    Self::Pair(_) => i < j,
+                    Self::Unpaired { suitedness, .. } => match suitedness {
+                        Suitedness::Suited => i == j,
+                        Suitedness::Offsuit => i != j,
+                        Suitedness::Any => true,
+                    },
+                };
+                if included {
+                    let combo = [Card::new(high, high_suit), Card::new(low, low_suit)];
+                    indices.extend(combo_index(combo));
+                }
+            }
+        }
+        indices
+    }
+
+    /// Every class from this one up to the strongest with the same shape, as
+    /// described by a trailing "+".
+    fn and_above(self) -> Vec<Self> {
+        match self {
+            Self::Pair(rank) => Rank::ALL_VARIANTS[rank as usize..]
+                .iter()
+                .map(|&rank| Self::Pair(rank))
+                .collect(),
+            Self::Unpaired {
+                high,
+                low,
+                suitedness,
+            } => Rank::ALL_VARIANTS[low as usize..high as usize]
+                .iter()
+                .map(|&low| Self::Unpaired {
+                    high,
+                    low,
+                    suitedness,
+                })
+                .collect(),
+        }
+    }
+
+    /// Every class between `self` and `other` inclusive, as described by a
+    /// dash. Returns `None` if the two classes don't share a shape.
+    fn span(self, other: Self) -> Option<Vec<Self>> {
+        match (self, other) {
+            (Self::Pair(a), Self::Pair(b)) => Some(
+                Rank::ALL_VARIANTS[a.min(b) as usize..=a.max(b) as usize]
+                    .iter()
+                    .map(|&rank| Self::Pair(rank))
+                    .collect(),
+            ),
+            (
+                Self::Unpaired {
+                    high,
+                    low: a,
+                    suitedness,
+                },
+                Self::Unpaired {
+                    high: other_high,
+                    low: b,
+                    suitedness: other_suitedness,
+                },
+            ) if high == other_high && suitedness == other_suitedness => Some(
+                Rank::ALL_VARIANTS[a.min(b) as usize..=a.max(b) as usize]
+                    .iter()
+                    .map(|&low| Self::Unpaired {
+                        high,
+                        low,
+                        suitedness,
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Parse one part of a range, without its weight, into combo indices.
+fn parse_hand(hand: &str) -> Option<Vec<usize>> {
+    let classes = if let Some((first, last)) = hand.split_once('-') {
+        Class::parse(first.trim())?.span(Class::parse(last.trim())?)?
+    } else if let Some(base) = hand.strip_suffix('+') {
+        Class::parse(base)?.and_above()
+    } else if let Some(class) = Class::parse(hand) {
+        vec![class]
+    } else {
+        let chars = hand.chars().collect::<Vec<_>>();
+        if chars.len() != 4 {
+            return None;
+        }
+        let first = Card::try_from_chars(chars[0], chars[1]).ok()?;
+        let second = Card::try_from_chars(chars[2], chars[3]).ok()?;
+        return combo_index([first, second]).map(|index| vec![index]);
+    };
+    Some(classes.into_iter().flat_map(Class::indices).collect())
+}
+
+fn parse_weight(original_input: &str, weight: &str) -> Result<f64, ParseRangeError> {
+    match weight.parse::<f64>() {
+        Ok(value) if value > 0.0 && value <= 1.0 => Ok(value),
+        _ => Err(ParseRangeError::InvalidWeight {
+            original_input: original_input.to_string(),
+            weight: weight.to_string(),
+        }),
+    }
+}
+
+/// Split a list of optional weights into maximal runs of adjacent, equal,
+/// present weights, as `(start, end, weight)` with `end` inclusive.
+fn runs(weights: &[Option<f64>]) -> Vec<(usize, usize, f64)> {
+    let mut runs: Vec<(usize, usize, f64)> = Vec::new();
+    for (i, weight) in weights.iter().enumerate() {
+        if let Some(weight) = *weight {
+            match runs.last_mut() {
+                Some(last) if last.1 + 1 == i && last.2 == weight => last.1 = i,
+                _ => runs.push((i, i, weight)),
+            }
+        }
+    }
+    runs
+}
+
+/// Write a run of classes from strongest to weakest, using "+" if the run
+/// starts at the strongest class of its shape.
+fn format_run(first: String, last: String, top: bool, len: usize, weight: f64) -> String {
+    let hands = match (len, top) {
+        (0, _) => first,
+        (_, true) => format!("{}+", last),
+        (_, false) => format!("{}-{}", first, last),
+    };
+    format!("{}{}", hands, format_weight(weight))
+}
+
+fn format_weight(weight: f64) -> String {
+    if weight < 1.0 {
+        format!(":{}", weight)
+    } else {
+        String::new()
+    }
+}
+
+/// The position of a combo within a [`Range`], or `None` if both cards are the
+/// same.
+pub(crate) fn combo_index(combo: [Card; 2]) -> Option<usize> {
+    let a = usize::from(combo[0].index());
+    let b = usize::from(combo[1].index());
+    let (low, high) = (a.min(b), a.max(b));
+    if low == high {
+        None
+    } else {
+        Some(high * (high - 1) / 2 + low)
+    }
+}
+
+/// Every combo, higher card first, along with its combo index, in order of
+/// combo index.
+fn all_combos() -> impl Iterator<Item = ([Card; 2], usize)> {
+    (1..52u8)
+        .flat_map(|high| (0..high).map(move |low| (high, low)))
+        .enumerate()
+        .map(|(index, (high, low))| ([Card::from_index(high), Card::from_index(low)], index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards;
+
+    fn parse(s: &str) -> Range { s.parse().expect("couldn't parse range") }
+
+    #[test]
+    fn combo_indices_are_dense() {
+        let combos = all_combos().collect::<Vec<_>>();
+        assert_eq!(combos.len(), COMBOS);
+        for (combo, index) in combos {
+            assert_eq!(combo_index(combo), Some(index));
+            assert_eq!(combo_index([combo[1], combo[0]]), Some(index));
+        }
+    }
+
+    #[test]
+    fn combo_counts() {
+        assert_eq!(parse("AA").combo_count(), 6);
+        assert_eq!(parse("AKs").combo_count(), 4);
+        assert_eq!(parse("AKo").combo_count(), 12);
+        assert_eq!(parse("AK").combo_count(), 16);
+        assert_eq!(parse("QQ+").combo_count(), 18);
+        assert_eq!(parse("99-QQ").combo_count(), 24);
+        assert_eq!(parse("A5s-A2s").combo_count(), 16);
+        assert_eq!(parse("ATo+").combo_count(), 48);
+        assert_eq!(parse("AhKh").combo_count(), 1);
+        assert_eq!(parse("22+").combo_count(), 78);
+        assert_eq!(parse("K2+").combo_count(), 11 * 16);
+        assert_eq!(parse("").combo_count(), 0);
+        assert!(parse(" , ").is_empty());
+        assert_eq!(parse("76s:0.5, KJo").weighted_combo_count(), 14.0);
+    }
+
+    #[test]
+    fn later_parts_override_earlier_parts() {
+        let range = parse("AK, AKs:0.25, AhKh");
+        assert_eq!(range.weight(cards!(Ace of Hearts, King of Hearts)), 1.0);
+        assert_eq!(range.weight(cards!(Ace of Clubs, King of Clubs)), 0.25);
+        assert_eq!(range.weight(cards!(Ace of Clubs, King of Hearts)), 1.0);
+        assert_eq!(range.weighted_combo_count(), 12.0 + 3.0 * 0.25 + 1.0);
+    }
+
+    #[test]
+    fn canonical_display() {
+        let cases = [
+            ("AA, KK, QQ", "QQ+"),
+            ("KK, QQ, JJ, TT, 99", "KK-99"),
+            ("22-44, AA", "AA, 44-22"),
+            ("AKs, AQs, AJs, ATs", "ATs+"),
+            ("A2s-A5s", "A5s-A2s"),
+            ("AKs, AKo", "AK"),
+            ("AK, AQs, AJs:0.5", "AK, AQs, AJs:0.5"),
+            ("AhKh, AcKc, AsKs, AdKd", "AKs"),
+            ("AKs, AdKd:0.5", "AdKd:0.5, AsKs, AhKh, AcKc"),
+            ("KQ, QJ, JT, T9", "KQ, QJ, JT, T9"),
+            ("", ""),
+        ];
+        for &(input, expected) in &cases {
+            assert_eq!(parse(input).to_string(), expected, "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let inputs = [
+            "QQ+, AKs, A5s-A2s, KJo, 76s:0.5, AhKh",
+            "22+, A2s+, K9s+, Q9s+, J9s+, T8s+, 98s, 87s, ATo+, KTo+, QTo+, JTo",
+            "AK:0.3, AKs:0.6, 2c2d, 7h2s:0.125, 55-33:0.75",
+        ];
+        for &input in &inputs {
+            let range = parse(input);
+            let printed = range.to_string();
+            assert_eq!(parse(&printed), range, "printed: {}", printed);
+            assert_eq!(parse(&printed).to_string(), printed);
+        }
+    }
+
+    #[test]
+    fn remove_dead_cards() {
+        let mut range = parse("AK, QQ");
+        range.remove_dead(cards!(Ace of Spades, Queen of Hearts));
+        assert_eq!(range.combo_count(), 12 + 3);
+        assert!(range
+            .combos()
+            .all(|(combo, _)| combo.iter().all(|card| card.rank_suit_string() != "As")));
+
+        assert!(range.remove(cards!(King of Hearts, Ace of Hearts)));
+        assert!(!range.remove(cards!(King of Hearts, Ace of Hearts)));
+        assert_eq!(range.combo_count(), 14);
+    }
+
+    #[test]
+    fn insert_and_combos() {
+        let mut range = Range::new();
+        assert!(range.insert(cards!(Two of Clubs, Ace of Diamonds), 0.5));
+        assert!(!range.insert(cards!(Two of Clubs, Two of Clubs), 1.0));
+        assert!(range.insert(cards!(King of Spades, King of Hearts), 2.0));
+        assert_eq!(
+            range.combos().collect::<Vec<_>>(),
+            vec![
+                (cards!(King of Spades, King of Hearts), 1.0),
+                (cards!(Ace of Diamonds, Two of Clubs), 0.5),
+            ]
+        );
+        assert_eq!(range.to_string(), "Ad2c:0.5, KsKh");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let invalid_hands = [
+            "AKx", "AAs", "A", "AKs-KQs", "QQ-AKs", "AhAh", "AhKx", "1K", "AKs+s",
+        ];
+        for &hand in &invalid_hands {
+            let input = format!("QQ+, {}", hand);
+            assert_eq!(
+                input.parse::<Range>(),
+                Err(ParseRangeError::InvalidHand {
+                    original_input: input.clone(),
+                    hand: hand.to_string(),
+                })
+            );
+        }
+        for &weight in &["0", "1.5", "-0.5", "half", "NaN", ""] {
+            let input = format!("76s:{}", weight);
+            assert_eq!(
+                input.parse::<Range>(),
+                Err(ParseRangeError::InvalidWeight {
+                    original_input: input.clone(),
+                    weight: weight.to_string(),
+                })
+            );
+        }
+    }
+}
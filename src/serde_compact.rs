@@ -0,0 +1,120 @@
+//! Opt-in compact forms of cards and card sets for `#[serde(with = ...)]`,
+//! enabled by the `serde` feature.
+//!
+//! On their own, [`Card`] and [`CardSet`] only use their compact integer forms
+//! in formats that aren't human-readable, like bincode. The modules here use
+//! them everywhere, so that a field in JSON can be a [`Card::index`] or the
+//! [`CardSet::bits`] of a set instead. In self-describing formats like JSON,
+//! either form is accepted back.
+//!
+//! # Example
+//!
+//! ```
+//! use poker::{card, Card, CardSet};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Seat {
+//!     #[serde(with = "poker::serde_compact::card")]
+//!     up_card: Card,
+//!     #[serde(with = "poker::serde_compact::card_set")]
+//!     hole_cards: CardSet,
+//! }
+//!
+//! let seat = Seat {
+//!     up_card: card!(Ace of Spades),
+//!     hole_cards: CardSet::from([card!(Two of Clubs)]),
+//! };
+//! let json = serde_json::to_string(&seat).expect("couldn't serialize seat");
+//! assert_eq!(json, r#"{"up_card":50,"hole_cards":1}"#);
+//! assert_eq!(serde_json::from_str::<Seat>(&json).expect("couldn't parse seat"), seat);
+//! ```
+//!
+//! [`Card`]: crate::Card
+//! [`CardSet`]: crate::CardSet
+//! [`Card::index`]: crate::Card::index
+//! [`CardSet::bits`]: crate::CardSet::bits
+
+/// A [`Card`](crate::Card) as its [`index`](crate::Card::index), from 0 to 51.
+pub mod card {
+    use serde::{Deserializer, Serializer};
+
+    use crate::{serialize::CardVisitor, Card};
+
+    /// Serialize `card` as its index.
+    pub fn serialize<S: Serializer>(card: &Card, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(card.index())
+    }
+
+    /// Deserialize a card from its index, or from a string like `"As"`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Card, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(CardVisitor)
+        } else {
+            deserializer.deserialize_u8(CardVisitor)
+        }
+    }
+}
+
+/// A [`CardSet`](crate::CardSet) as its [`bits`](crate::CardSet::bits).
+pub mod card_set {
+    use serde::{Deserializer, Serializer};
+
+    use crate::{serialize::CardSetVisitor, CardSet};
+
+    /// Serialize `set` as its bits.
+    pub fn serialize<S: Serializer>(set: &CardSet, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(set.bits())
+    }
+
+    /// Deserialize a card set from its bits, or from a sequence of cards.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<CardSet, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(CardSetVisitor)
+        } else {
+            deserializer.deserialize_u64(CardSetVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{card, deck, Card, CardSet};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Compact {
+        #[serde(with = "super::card")]
+        card: Card,
+        #[serde(with = "super::card_set")]
+        set: CardSet,
+    }
+
+    #[test]
+    fn json_uses_integers() {
+        for card in deck::generate() {
+            let value = Compact {
+                card,
+                set: CardSet::from([card, card!(Ace of Spades)]),
+            };
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(
+                json,
+                format!(r#"{{"card":{},"set":{}}}"#, card.index(), value.set.bits())
+            );
+            assert_eq!(serde_json::from_str::<Compact>(&json).unwrap(), value);
+        }
+
+        // The readable forms are still accepted
+        let readable = r#"{"card":"Kd","set":["2c","As"]}"#;
+        assert_eq!(
+            serde_json::from_str::<Compact>(readable).unwrap(),
+            Compact {
+                card: card!(King of Diamonds),
+                set: CardSet::from([card!(Two of Clubs), card!(Ace of Spades)]),
+            }
+        );
+        assert!(serde_json::from_str::<Compact>(r#"{"card":52,"set":0}"#).is_err());
+    }
+}
@@ -0,0 +1,399 @@
+//! [`Serialize`] and [`Deserialize`] implementations for the card and
+//! evaluation types, enabled by the `serde` feature. The error types derive
+//! theirs alongside their definitions, apart from [`OriginalInput`], which is
+//! written as a string whether or not the `std` feature keeps the input, so
+//! that errors look the same either way.
+//!
+//! In human-readable formats like JSON, cards, ranks, and suits are written as
+//! the same characters that they are parsed from, like `"As"`, `"A"`, and
+//! `"s"`, and card sets as sequences of cards. Compact formats like bincode
+//! get small integers instead: [`Card::index`], the position of a rank or suit
+//! in the order of [`Card::index`], and [`CardSet::bits`]. An [`Eval`] is
+//! always its [`strength`](Eval::strength), which doesn't change between
+//! versions of this crate.
+
+use core::{convert::TryFrom, fmt};
+
+use serde::{
+    de::{self, Unexpected, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    card::{Card, CardSet, Rank, RankSuit, Suit},
+    error::OriginalInput,
+    evaluate::{Eval, EvalClass},
+};
+
+impl Serialize for Rank {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_char(self.as_char())
+        } else {
+            serializer.serialize_u8(*self as u8)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Rank {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct RankVisitor;
+
+        impl<'de> Visitor<'de> for RankVisitor {
+            type Value = Rank;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("one of the characters \"23456789TJQKA\", or an integer from 0 to 12")
+            }
+
+            fn visit_char<E: de::Error>(self, v: char) -> Result<Self::Value, E> {
+                Rank::try_from(v).map_err(|_| E::invalid_value(Unexpected::Char(v), &self))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                let mut chars = v.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => self.visit_char(c),
+                    _ => Err(E::invalid_value(Unexpected::Str(v), &self)),
+                }
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                usize::try_from(v)
+                    .ok()
+                    .and_then(|i| Rank::ALL_VARIANTS.get(i).copied())
+                    .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_char(RankVisitor)
+        } else {
+            deserializer.deserialize_u8(RankVisitor)
+        }
+    }
+}
+
+impl Serialize for Suit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_char(self.as_char())
+        } else {
+            serializer.serialize_u8(*self as u8)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Suit {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SuitVisitor;
+
+        impl<'de> Visitor<'de> for SuitVisitor {
+            type Value = Suit;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("one of the characters \"chsd\", or an integer from 0 to 3")
+            }
+
+            fn visit_char<E: de::Error>(self, v: char) -> Result<Self::Value, E> {
+                Suit::try_from(v).map_err(|_| E::invalid_value(Unexpected::Char(v), &self))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                let mut chars = v.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => self.visit_char(c),
+                    _ => Err(E::invalid_value(Unexpected::Str(v), &self)),
+                }
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                usize::try_from(v)
+                    .ok()
+                    .and_then(|i| Suit::ALL_VARIANTS.get(i).copied())
+                    .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_char(SuitVisitor)
+        } else {
+            deserializer.deserialize_u8(SuitVisitor)
+        }
+    }
+}
+
+impl Serialize for Card {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            RankSuit(*self).with_str(|s| serializer.serialize_str(s))
+        } else {
+            serializer.serialize_u8(self.index())
+        }
+    }
+}
+
+/// Accepts a [`Card`] written either way, so [`serde_compact`] can share it.
+///
+/// [`serde_compact`]: crate::serde_compact
+pub(crate) struct CardVisitor;
+
+impl<'de> Visitor<'de> for CardVisitor {
+    type Value = Card;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a card like \"As\", or an integer from 0 to 51")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        match u8::try_from(v) {
+            Ok(index) if index < 52 => Ok(Card::from_index(index)),
+            _ => Err(E::invalid_value(Unexpected::Unsigned(v), &self)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Card {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(CardVisitor)
+        } else {
+            deserializer.deserialize_u8(CardVisitor)
+        }
+    }
+}
+
+impl Serialize for CardSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let mut seq = serializer.serialize_seq(Some(self.len()))?;
+            for card in *self {
+                seq.serialize_element(&card)?;
+            }
+            seq.end()
+        } else {
+            serializer.serialize_u64(self.bits())
+        }
+    }
+}
+
+/// Accepts a [`CardSet`] written either way, like [`CardVisitor`].
+pub(crate) struct CardSetVisitor;
+
+impl<'de> Visitor<'de> for CardSetVisitor {
+    type Value = CardSet;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence of cards, or the bits of a card set")
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut set = CardSet::new();
+        while let Some(card) = seq.next_element()? {
+            set.insert(card);
+        }
+        Ok(set)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        let set = CardSet::from_bits(v);
+        if set.bits() == v {
+            Ok(set)
+        } else {
+            Err(E::invalid_value(Unexpected::Unsigned(v), &self))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CardSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_seq(CardSetVisitor)
+        } else {
+            deserializer.deserialize_u64(CardSetVisitor)
+        }
+    }
+}
+
+impl Serialize for Eval {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(self.strength())
+    }
+}
+
+impl<'de> Deserialize<'de> for Eval {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let strength = u16::deserialize(deserializer)?;
+        Eval::from_strength(strength).ok_or_else(|| {
+            de::Error::invalid_value(
+                Unexpected::Unsigned(strength.into()),
+                &"a strength from 1 to 7462",
+            )
+        })
+    }
+}
+
+/// The same enum as [`EvalClass`], for deriving its implementations without
+/// touching `class.rs`, which the build script also compiles.
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "EvalClass")]
+enum EvalClassDef {
+    HighCard { high_rank: Rank },
+    Pair { pair: Rank },
+    TwoPair { first_pair: Rank, second_pair: Rank },
+    ThreeOfAKind { trips: Rank },
+    Straight { high_rank: Rank },
+    Flush { high_rank: Rank },
+    FullHouse { trips: Rank, pair: Rank },
+    FourOfAKind { quads: Rank },
+    StraightFlush { high_rank: Rank },
+}
+
+impl Serialize for EvalClass {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        EvalClassDef::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for EvalClass {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        EvalClassDef::deserialize(deserializer)
+    }
+}
+
+impl Serialize for OriginalInput {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[cfg(feature = "std")]
+        let input = self.as_str();
+        #[cfg(not(feature = "std"))]
+        let input = "";
+        serializer.serialize_str(input)
+    }
+}
+
+impl<'de> Deserialize<'de> for OriginalInput {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct OriginalInputVisitor;
+
+        impl<'de> Visitor<'de> for OriginalInputVisitor {
+            type Value = OriginalInput;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("the input that an error was encountered in")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> { Ok(v.into()) }
+        }
+
+        deserializer.deserialize_str(OriginalInputVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Compact, Configure, Readable, Token};
+
+    use super::*;
+    use crate::{card, cards, evaluate::tests::EVALUATOR, EvalError, ParseCardError};
+
+    #[test]
+    fn cards_ranks_and_suits() {
+        let card = card!(Ace of Spades);
+        assert_tokens(&card.readable(), &[Token::Str("As")]);
+        assert_tokens(&card.compact(), &[Token::U8(card.index())]);
+        assert_tokens(&Rank::Ten.readable(), &[Token::Char('T')]);
+        assert_tokens(&Rank::Ten.compact(), &[Token::U8(8)]);
+        assert_tokens(&Suit::Diamonds.readable(), &[Token::Char('d')]);
+        assert_tokens(&Suit::Diamonds.compact(), &[Token::U8(3)]);
+
+        for index in 0..52 {
+            let card = Card::from_index(index);
+            let json = serde_json::to_string(&card).unwrap();
+            assert_eq!(json, format!("\"{}\"", card.rank_suit_string()));
+            assert_eq!(serde_json::from_str::<Card>(&json).unwrap(), card);
+        }
+
+        assert_de_tokens_error::<Readable<Card>>(
+            &[Token::Str("1c")],
+            "Error parsing input '1c' as a Card: Invalid rank character '1', expected one of \
+             [23456789TJQKA]",
+        );
+        assert_de_tokens_error::<Compact<Card>>(
+            &[Token::U8(52)],
+            "invalid value: integer `52`, expected a card like \"As\", or an integer from 0 to 51",
+        );
+        assert!(serde_json::from_str::<Rank>("\"AK\"").is_err());
+        assert!(serde_json::from_str::<Suit>("\"S\"").is_err());
+    }
+
+    #[test]
+    fn card_sets() {
+        let set = CardSet::from(cards!(Ace of Spades, Two of Clubs));
+        assert_tokens(
+            &set.readable(),
+            &[
+                Token::Seq { len: Some(2) },
+                Token::Str("2c"),
+                Token::Str("As"),
+                Token::SeqEnd,
+            ],
+        );
+        assert_tokens(&set.compact(), &[Token::U64(set.bits())]);
+        assert_de_tokens_error::<Compact<CardSet>>(
+            &[Token::U64(1 << 52)],
+            "invalid value: integer `4503599627370496`, expected a sequence of cards, or the bits \
+             of a card set",
+        );
+    }
+
+    #[test]
+    fn evals_are_their_strength() {
+        let eval = EVALUATOR
+            .evaluate(cards!("Jd 4c Jc 4s Jh").try_collect::<Vec<_>>().unwrap())
+            .unwrap();
+        assert_tokens(&eval, &[Token::U16(eval.strength())]);
+        for eval in Eval::all() {
+            let json = serde_json::to_string(&eval).unwrap();
+            assert_eq!(serde_json::from_str::<Eval>(&json).unwrap(), eval);
+            let json = serde_json::to_string(&eval.class()).unwrap();
+            assert_eq!(
+                serde_json::from_str::<EvalClass>(&json).unwrap(),
+                eval.class()
+            );
+        }
+        assert_eq!(
+            serde_json::to_string(&eval.class()).unwrap(),
+            r#"{"FullHouse":{"trips":"J","pair":"4"}}"#
+        );
+        assert!(serde_json::from_str::<Eval>("0").is_err());
+        assert!(serde_json::from_str::<Eval>("7463").is_err());
+    }
+
+    #[test]
+    fn errors() {
+        let error = "10c".parse::<Card>().unwrap_err();
+        let json = serde_json::to_string(&error).unwrap();
+        assert_eq!(
+            json,
+            r#"{"InvalidLength":{"original_input":"10c","position":null,"length":3}}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<ParseCardError>(&json).unwrap(),
+            error
+        );
+
+        let error = EVALUATOR
+            .evaluate(
+                cards!(Ace of Spades, Ace of Spades, Two of Clubs, Three of Clubs, Four of Clubs),
+            )
+            .unwrap_err();
+        let json = serde_json::to_string(&error).unwrap();
+        assert_eq!(json, r#"{"CardsNotUnique":["As"]}"#);
+        assert_eq!(serde_json::from_str::<EvalError>(&json).unwrap(), error);
+    }
+}
@@ -0,0 +1,581 @@
+//! The 169 classes of Texas Holdem starting hands, such as "AKs", "72o", or
+//! "TT".
+//!
+//! Before the flop, suits only matter in whether the two hole cards share one,
+//! so the 1,326 possible starting hands fall into 169 classes: 13 pairs, 78
+//! suited hands, and 78 offsuit hands. A [`StartingHand`] is one of these
+//! classes, and can be placed on the familiar 13x13 preflop chart with
+//! [`StartingHand::index`].
+
+use std::{convert::TryFrom, fmt, str::FromStr};
+
+use crate::{equity::Equity, error::ParseStartingHandError, Card, Rank, Suit};
+
+/// The number of two-card runouts against a random hand for a single combo:
+/// 1,225 hands for the opponent, and 1,712,304 boards for each.
+const RUNOUTS: u64 = 1225 * 1_712_304;
+
+/// One of the 169 classes of two-card Texas Holdem starting hands.
+///
+/// A starting hand is either a pair, such as "TT", a suited hand, such as
+/// "AKs", or an offsuit hand, such as "72o". It can be built from a specific
+/// pair of cards with [`from_cards`](Self::from_cards), or parsed from that
+/// notation with [`FromStr`], and is printed the same way.
+///
+/// # Grid layout
+///
+/// [`index`](Self::index) gives every class a position from 0 to 168 in the
+/// conventional 13x13 preflop chart, read row by row. Rows and columns both
+/// run from aces down to deuces, pairs lie along the diagonal, suited hands
+/// lie above it, and offsuit hands lie below it. So "AA" is 0, "AKs" is 1,
+/// "AKo" is 13, and "22" is 168.
+///
+/// # Example
+///
+/// ```
+/// use poker::{cards, StartingHand};
+///
+/// let hand = StartingHand::from_cards(cards!(Ace of Hearts, King of Hearts)).unwrap();
+/// assert_eq!(hand.to_string(), "AKs");
+/// assert_eq!(hand, "AKs".parse().unwrap());
+/// assert_eq!(hand.index(), 1);
+/// assert_eq!(hand.combo_count(), 4);
+/// assert!(hand.combos().any(|combo| combo == cards!(Ace of Spades, King of Spades)));
+///
+/// // Heads-up, all in against a random hand
+/// let equity = hand.equity().equity();
+/// assert!(equity > 0.67 && equity < 0.68);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct StartingHand {
+    high: Rank,
+    low: Rank,
+    // Always false for pairs
+    suited: bool,
+}
+
+impl StartingHand {
+    /// The number of distinct starting hands.
+    pub const COUNT: usize = 169;
+
+    /// The pair of the given rank.
+    pub const fn pair(rank: Rank) -> Self {
+        Self {
+            high: rank,
+            low: rank,
+            suited: false,
+        }
+    }
+
+    /// The suited hand with the given ranks, in either order. Returns `None`
+    /// if the ranks are the same.
+    pub fn suited(first: Rank, second: Rank) -> Option<Self> { Self::unpaired(first, second, true) }
+
+    /// The offsuit hand with the given ranks, in either order. Returns `None`
+    /// if the ranks are the same.
+    pub fn offsuit(first: Rank, second: Rank) -> Option<Self> {
+        Self::unpaired(first, second, false)
+    }
+
+    fn unpaired(first: Rank, second: Rank, suited: bool) -> Option<Self> {
+        if first == second {
+            None
+        } else {
+            Some(Self {
+                high: first.max(second),
+                low: first.min(second),
+                suited,
+            })
+        }
+    }
+
+    /// The class of the given hole cards, in either order. Returns `None` if
+    /// both cards are the same.
+    pub fn from_cards(cards: [Card; 2]) -> Option<Self> {
+        let [first, second] = cards;
+        if first == second {
+            None
+        } else if first.rank() == second.rank() {
+            Some(Self::pair(first.rank()))
+        } else {
+            Self::unpaired(first.rank(), second.rank(), first.suit() == second.suit())
+        }
+    }
+
+    /// The starting hand at the given position in the grid described by
+    /// [`index`](Self::index). Returns `None` if `index` is 169 or more.
+    pub fn from_index(index: usize) -> Option<Self> {
+        if index >= Self::COUNT {
+            return None;
+        }
+        let row = Rank::ALL_VARIANTS[12 - index / 13];
+        let column = Rank::ALL_VARIANTS[12 - index % 13];
+        match row.cmp(&column) {
+            std::cmp::Ordering::Equal => Some(Self::pair(row)),
+            std::cmp::Ordering::Greater => Self::suited(row, column),
+            std::cmp::Ordering::Less => Self::offsuit(row, column),
+        }
+    }
+
+    /// Iterate over all 169 starting hands, in order of
+    /// [`index`](Self::index).
+    pub fn all() -> impl Iterator<Item = Self> { (0..Self::COUNT).filter_map(Self::from_index) }
+
+    /// The higher rank of the two cards, or the rank of both for a pair.
+    pub const fn high(self) -> Rank { self.high }
+
+    /// The lower rank of the two cards, or the rank of both for a pair.
+    pub const fn low(self) -> Rank { self.low }
+
+    /// Whether both cards have the same rank.
+    pub const fn is_pair(self) -> bool { self.high as u8 == self.low as u8 }
+
+    /// Whether both cards have the same suit.
+    pub const fn is_suited(self) -> bool { self.suited }
+
+    /// Whether the cards have different ranks and different suits.
+    pub const fn is_offsuit(self) -> bool { !self.suited && !self.is_pair() }
+
+    /// The position of this starting hand in the 13x13 grid, from 0 to 168.
+    /// See [the type-level documentation](Self#grid-layout) for the layout.
+    pub const fn index(self) -> usize {
+        let high = 12 - self.high as usize;
+        let low = 12 - self.low as usize;
+        if self.suited {
+            high * 13 + low
+        } else {
+            low * 13 + high
+        }
+    }
+
+    /// The number of specific combos in this class: 6 for a pair, 4 for a
+    /// suited hand, and 12 for an offsuit hand.
+    pub const fn combo_count(self) -> usize {
+        if self.is_pair() {
+            6
+        } else if self.suited {
+            4
+        } else {
+            12
+        }
+    }
+
+    /// Iterate over every specific combo in this class, with the higher card
+    /// first.
+    pub fn combos(self) -> impl Iterator<Item = [Card; 2]> {
+        let suits = Suit::ALL_VARIANTS.iter().enumerate();
+        suits.clone().flat_map(move |(i, &high_suit)| {
+            suits.clone().filter_map(move |(j, &low_suit)| {
+                let included = if self.is_pair() {
+                    i < j
+                } else {
+                    self.suited == (i == j)
+                };
+                if included {
+                    Some([
+                        Card::new(self.high, high_suit),
+                        Card::new(self.low, low_suit),
+                    ])
+                } else {
+                    None
+                }
+            })
+        })
+    }
+
+    /// Whether the given hole cards, in either order, belong to this class.
+    pub fn contains(self, cards: [Card; 2]) -> bool { Self::from_cards(cards) == Some(self) }
+
+    /// The exact equity of any combo in this class when all in before the flop
+    /// against a single random hand, over every hand the opponent could hold
+    /// and every board.
+    ///
+    /// These results were precomputed with this crate's [`Evaluator`], and
+    /// cover 2,097,572,400 runouts each.
+    ///
+    /// [`Evaluator`]: crate::Evaluator
+    pub fn equity(self) -> Equity {
+        let (wins, ties) = EQUITY[self.index()];
+        Equity::heads_up(wins, ties, RUNOUTS)
+    }
+}
+
+impl fmt::Display for StartingHand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let suffix = if self.is_pair() {
+            ""
+        } else if self.suited {
+            "s"
+        } else {
+            "o"
+        };
+        write!(f, "{}{}{}", self.high, self.low, suffix)
+    }
+}
+
+impl FromStr for StartingHand {
+    type Err = ParseStartingHandError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseStartingHandError::InvalidHand {
+            original_input: s.to_string(),
+        };
+        let mut chars = s.chars();
+        let first = chars.next().and_then(|c| Rank::try_from(c).ok());
+        let second = chars.next().and_then(|c| Rank::try_from(c).ok());
+        let (first, second) = first.zip(second).ok_or_else(invalid)?;
+        let hand = match (chars.next(), first == second) {
+            (None, true) => Some(Self::pair(first)),
+            (Some('s'), false) => Self::suited(first, second),
+            (Some('o'), false) => Self::offsuit(first, second),
+            _ => None,
+        };
+        match (hand, chars.next()) {
+            (Some(hand), None) => Ok(hand),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// The wins and ties of each starting hand against a random hand, out of
+/// [`RUNOUTS`], in order of [`StartingHand::index`].
+const EQUITY: [(u64, u64); StartingHand::COUNT] = [
+    (1781508418, 11402312),
+    (1389004215, 34610976),
+    (1370002117, 37553414),
+    (1350786717, 41744140),
+    (1331725962, 46711746),
+    (1290209940, 53342951),
+    (1269195105, 60242341),
+    (1245678450, 67008466),
+    (1220344506, 72447218),
+    (1217936943, 77979612),
+    (1198506708, 79533094),
+    (1181668536, 79089680),
+    (1164284001, 78559848),
+    (1352291878, 35687839),
+    (1722470558, 11676960),
+    (1309061790, 41615142),
+    (1289515286, 45759944),
+    (1270857555, 50404375),
+    (1229977429, 56648553),
+    (1191219968, 63847580),
+    (1171417014, 70957375),
+    (1149567662, 77025547),
+    (1129204024, 82185635),
+    (1109381784, 83726409),
+    (1092208613, 83267308),
+    (1074795095, 82718864),
+    (1332142795, 38723369),
+    (1267610255, 42939290),
+    (1670338644, 12299076),
+    (1239054394, 49852123),
+    (1220171285, 54407652),
+    (1179313858, 60474078),
+    (1141436364, 67152284),
+    (1101718156, 74622259),
+    (1084009562, 81105669),
+    (1063751334, 86250529),
+    (1043815735, 87773537),
+    (1026518567, 87294132),
+    (1008970414, 86722846),
+    (1311720573, 43129563),
+    (1246822903, 47298240),
+    (1193649409, 51535593),
+    (1618339604, 13277356),
+    (1177887186, 57602534),
+    (1135038051, 65045137),
+    (1097274797, 71484894),
+    (1058318785, 78462767),
+    (1018879951, 85231180),
+    (1003078936, 90862846),
+    (983104243, 92368088),
+    (965759044, 91868379),
+    (948153923, 91274251),
+    (1291435142, 48393949),
+    (1226959959, 52214074),
+    (1173537003, 56357281),
+    (1129039428, 59642287),
+    (1566053110, 14746566),
+    (1098642842, 69247375),
+    (1059465943, 76570746),
+    (1020494848, 83391254),
+    (981668027, 89796157),
+    (942628592, 95533249),
+    (927212359, 97596865),
+    (909895095, 97076852),
+    (892310673, 96459882),
+    (1247001366, 55504495),
+    (1183191163, 58911625),
+    (1129794528, 62861351),
+    (1083173398, 67618021),
+    (1044920282, 71987711),
+    (1503238936, 16428186),
+    (1024795719, 81573810),
+    (985655348, 89249246),
+    (947078416, 95528642),
+    (908515397, 101068049),
+    (868544377, 102984434),
+    (855951949, 103094363),
+    (838461663, 102454551),
+    (1224440175, 62862129),
+    (1141742922, 66641512),
+    (1089280295, 70028300),
+    (1042779957, 74526982),
+    (1003019374, 79840679),
+    (966316620, 85106929),
+    (1441396848, 18695790),
+    (958257237, 94484411),
+    (919124997, 101722112),
+    (880780296, 107166353),
+    (841184813, 109025471),
+    (803004465, 108693752),
+    (790348964, 108755450),
+    (1199171976, 70129398),
+    (1120467131, 74254989),
+    (1046780178, 78084287),
+    (1001090143, 82048768),
+    (961312472, 87190712),
+    (924443362, 93373294),
+    (895522755, 98901298),
+    (1378636878, 21423310),
+    (898379280, 106652749),
+    (859564300, 113123404),
+    (820337671, 114963889),
+    (782469104, 114627274),
+    (743376257, 113942467),
+    (1171920110, 76022178),
+    (1096969965, 80805137),
+    (1027737078, 85058721),
+    (958874577, 89387379),
+    (919746160, 94133959),
+    (883149152, 100178053),
+    (853640324, 106737798),
+    (831767413, 111965689),
+    (1315178390, 24530044),
+    (846332445, 116843119),
+    (807166360, 119659864),
+    (769503449, 119483186),
+    (730742786, 118782017),
+    (1169234974, 81983213),
+    (1075088026, 86422307),
+    (1005974746, 90660555),
+    (941907501, 95491577),
+    (877990854, 100409701),
+    (841896769, 106238788),
+    (812626207, 112692288),
+    (790253778, 119028199),
+    (776369338, 122982645),
+    (1250992922, 28731922),
+    (808247748, 122535513),
+    (771039857, 123097534),
+    (732672291, 122489533),
+    (1148061584, 83768953),
+    (1053508976, 88195273),
+    (984280928, 92415629),
+    (920178475, 97228759),
+    (861166500, 102727325),
+    (798877137, 108455313),
+    (770001459, 114847103),
+    (748030580, 121159938),
+    (734213031, 126168959),
+    (735719749, 129230044),
+    (1180021110, 32147698),
+    (749407203, 122271282),
+    (711453440, 122123616),
+    (1129654161, 83453609),
+    (1034758983, 87864179),
+    (965405538, 92064087),
+    (901258949, 96856769),
+    (842284099, 102334887),
+    (785077590, 108721395),
+    (728903246, 114693723),
+    (707257588, 121002505),
+    (693662773, 126183313),
+    (695659494, 130040672),
+    (672613963, 129193193),
+    (1108341244, 35819814),
+    (694128063, 121342843),
+    (1110608066, 83116441),
+    (1015702916, 87508267),
+    (946213445, 91685171),
+    (882013792, 96454849),
+    (823068840, 101909963),
+    (765970787, 108273467),
+    (714995931, 114982393),
+    (665146081, 120541920),
+    (651897304, 125706786),
+    (654312693, 129665137),
+    (631734409, 129308773),
+    (613318625, 128529945),
+    (1035889822, 39805340),
+];
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use super::*;
+    use crate::{
+        cards, deck,
+        evaluate::{tests::EVALUATOR, utils},
+        isomorphism,
+    };
+
+    fn parse(s: &str) -> StartingHand { s.parse().expect("couldn't parse starting hand") }
+
+    #[test]
+    fn indices_follow_the_grid() {
+        let hands: Vec<StartingHand> = StartingHand::all().collect();
+        assert_eq!(hands.len(), StartingHand::COUNT);
+        for (index, hand) in hands.iter().enumerate() {
+            assert_eq!(hand.index(), index);
+        }
+        assert_eq!(parse("AA").index(), 0);
+        assert_eq!(parse("AKs").index(), 1);
+        assert_eq!(parse("A2s").index(), 12);
+        assert_eq!(parse("AKo").index(), 13);
+        assert_eq!(parse("KK").index(), 14);
+        assert_eq!(parse("32o").index(), 167);
+        assert_eq!(parse("22").index(), 168);
+        assert_eq!(StartingHand::from_index(169), None);
+        assert_eq!(hands.iter().filter(|hand| hand.is_pair()).count(), 13);
+        assert_eq!(hands.iter().filter(|hand| hand.is_suited()).count(), 78);
+        assert_eq!(hands.iter().filter(|hand| hand.is_offsuit()).count(), 78);
+    }
+
+    #[test]
+    fn combos_cover_every_hand_once() {
+        let mut seen = HashSet::new();
+        for hand in StartingHand::all() {
+            let combos: Vec<[Card; 2]> = hand.combos().collect();
+            assert_eq!(combos.len(), hand.combo_count());
+            for combo in combos {
+                assert!(combo[0].rank() >= combo[1].rank());
+                assert_eq!(StartingHand::from_cards(combo), Some(hand));
+                assert!(hand.contains([combo[1], combo[0]]));
+                assert!(seen.insert(crate::range::combo_index(combo).unwrap()));
+            }
+        }
+        assert_eq!(seen.len(), 1326);
+    }
+
+    #[test]
+    fn from_cards() {
+        let hand = |cards| StartingHand::from_cards(cards).unwrap().to_string();
+        assert_eq!(hand(cards!(Seven of Clubs, Two of Diamonds)), "72o");
+        assert_eq!(hand(cards!(Two of Hearts, Seven of Hearts)), "72s");
+        assert_eq!(hand(cards!(Ten of Spades, Ten of Hearts)), "TT");
+        assert_eq!(
+            StartingHand::from_cards(cards!(Ten of Spades, Ten of Spades)),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_and_display() {
+        for hand in StartingHand::all() {
+            assert_eq!(parse(&hand.to_string()), hand);
+        }
+        assert_eq!(parse("KAs"), parse("AKs"));
+        for input in ["", "A", "AK", "AA s", "AAs", "AKx", "AKss", "1Ks", "TTo"] {
+            assert_eq!(
+                input.parse::<StartingHand>(),
+                Err(ParseStartingHandError::InvalidHand {
+                    original_input: input.to_string(),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn equity_against_a_random_hand() {
+        let equity = |s| parse(s).equity().equity();
+        assert!((equity("AA") - 0.8520).abs() < 0.0001);
+        assert!((equity("72o") - 0.3458).abs() < 0.0001);
+        assert!(equity("AKs") > equity("AKo"));
+        for hand in StartingHand::all() {
+            assert_eq!(hand.equity().total(), RUNOUTS);
+        }
+        // Over every pair of combos, the equities of both sides add up to one
+        let total: f64 = StartingHand::all()
+            .map(|hand| hand.equity().equity() * hand.combo_count() as f64)
+            .sum();
+        assert!((total / 1326.0 - 0.5).abs() < 1e-9);
+    }
+
+    /// Regenerate the equity table by showing down every combo against every
+    /// other combo on every board, using one board from each class of
+    /// isomorphic boards.
+    #[test]
+    #[ignore = "evaluates over a hundred million hands, run it in release mode"]
+    fn equity_table_matches_evaluator() {
+        let deck: Vec<Card> = deck::generate().collect();
+        let mut boards = HashMap::new();
+        for board in utils::const_combos::<_, 5>(&deck) {
+            let (mut canonical, _) = isomorphism::canonicalize(&[&board]);
+            *boards.entry(canonical.remove(0)).or_insert(0) += 1;
+        }
+
+        let mut wins = [0; StartingHand::COUNT];
+        let mut ties = [0; StartingHand::COUNT];
+        for (board, count) in boards {
+            let rest: Vec<Card> = deck
+                .iter()
+                .copied()
+                .filter(|card| !board.contains(card))
+                .collect();
+            let mut hands: Vec<(u16, [Card; 2])> = utils::const_combos::<_, 2>(&rest)
+                .map(|combo| {
+                    let cards = [
+                        combo[0], combo[1], board[0], board[1], board[2], board[3], board[4],
+                    ];
+                    (EVALUATOR.evaluate(cards).unwrap().strength(), combo)
+                })
+                .collect();
+            hands.sort_unstable_by_key(|&(strength, _)| strength);
+
+            // Count the weaker and tied hands that share no cards with each
+            // hand, by removing those that share either card from the totals
+            let mut weaker = 0;
+            let mut weaker_with = [0; 52];
+            let mut start = 0;
+            while start < hands.len() {
+                let strength = hands[start].0;
+                let end = start
+                    + hands[start..]
+                        .iter()
+                        .take_while(|h| h.0 == strength)
+                        .count();
+                let group = &hands[start..end];
+                let mut tied_with = [0; 52];
+                for (_, combo) in group {
+                    for card in combo {
+                        tied_with[usize::from(card.index())] += 1;
+                    }
+                }
+                for &(_, combo) in group {
+                    let index = StartingHand::from_cards(combo).unwrap().index();
+                    let [a, b] = combo.map(|card| usize::from(card.index()));
+                    wins[index] += count * (weaker - weaker_with[a] - weaker_with[b]);
+                    ties[index] += count * (group.len() as u64 + 1 - tied_with[a] - tied_with[b]);
+                }
+                weaker += group.len() as u64;
+                for (slot, tied) in weaker_with.iter_mut().zip(tied_with.iter()) {
+                    *slot += tied;
+                }
+                start = end;
+            }
+        }
+
+        let table: Vec<(u64, u64)> = StartingHand::all()
+            .map(|hand| {
+                let combos = hand.combo_count() as u64;
+                assert_eq!(wins[hand.index()] % combos, 0);
+                assert_eq!(ties[hand.index()] % combos, 0);
+                (wins[hand.index()] / combos, ties[hand.index()] / combos)
+            })
+            .collect();
+        assert_eq!(table, EQUITY);
+    }
+}